@@ -0,0 +1,139 @@
+use std::{
+    collections::HashMap,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    error::MagicedenError,
+    types::{
+        BidAction, BidInstruction, BidManager, BidPlan, BidStep, EscrowAction, InstructionResponse,
+        InstructionsBuyCancelRequestArgs, InstructionsBuyChangePriceRequestArgs,
+        InstructionsBuyRequestArgs, InstructionsDepositRequestArgs,
+        InstructionsWithdrawRequestArgs,
+    },
+    Client,
+};
+
+/// Drives a `BidManager` with `Tokens::offers_received`, `Wallets::escrow_balance`
+/// and the buy, buy_change_price, buy_cancel, deposit and withdraw instructions
+pub struct Bids<'c> {
+    pub client: &'c Client,
+}
+
+impl<'c> Bids<'c> {
+    pub fn new(client: &'c Client) -> Self {
+        Self { client }
+    }
+
+    /// Fetch the offers of every managed token and the escrow balance and reconcile the manager
+    pub async fn plan(&self, manager: &BidManager) -> Result<BidPlan, MagicedenError> {
+        let tokens = self.client.tokens();
+        let mut offers = HashMap::new();
+        for token_mint in manager.token_mints() {
+            offers.insert(
+                token_mint.to_string(),
+                tokens.offers_received(token_mint).await?,
+            );
+        }
+        let escrow = self.client.wallets().escrow_balance(&manager.buyer).await?;
+
+        Ok(manager.reconcile(&offers, escrow.buyer_escrow, now()))
+    }
+
+    /// Request the instructions of a plan: escrow deposit first, then the bids, escrow withdrawal last
+    pub async fn instructions(
+        &self,
+        manager: &BidManager,
+        plan: &BidPlan,
+    ) -> Result<Vec<BidInstruction>, MagicedenError> {
+        let mut steps: Vec<BidStep> = vec![];
+        if let Some(deposit @ EscrowAction::Deposit { .. }) = &plan.escrow {
+            steps.push(BidStep::Escrow(deposit.clone()));
+        }
+        steps.extend(plan.actions.iter().cloned().map(BidStep::Bid));
+        if let Some(withdraw @ EscrowAction::Withdraw { .. }) = &plan.escrow {
+            steps.push(BidStep::Escrow(withdraw.clone()));
+        }
+
+        let mut instructions = vec![];
+        for step in steps {
+            let instruction = self.instruction(manager, plan, &step).await?;
+            instructions.push(BidInstruction { step, instruction });
+        }
+        Ok(instructions)
+    }
+
+    async fn instruction(
+        &self,
+        manager: &BidManager,
+        plan: &BidPlan,
+        step: &BidStep,
+    ) -> Result<InstructionResponse, MagicedenError> {
+        let instructions = self.client.instructions();
+        let buyer = manager.buyer.as_str();
+        let auction_house = manager.auction_house_address.as_str();
+
+        match step {
+            BidStep::Bid(BidAction::Place { token_mint, price }) => {
+                let mut args = InstructionsBuyRequestArgs::default();
+                args.buyer(buyer)
+                    .auction_house_address(auction_house)
+                    .token_mint(token_mint.as_str())
+                    .price(*price);
+                if let Some(expiry) = plan.expiry {
+                    args.expiry(expiry);
+                }
+                instructions.buy(args.build()?).await
+            }
+            BidStep::Bid(BidAction::ChangePrice {
+                token_mint,
+                old,
+                new,
+            }) => {
+                let mut args = InstructionsBuyChangePriceRequestArgs::default();
+                args.buyer(buyer)
+                    .auction_house_address(auction_house)
+                    .token_mint(token_mint.as_str())
+                    .price(*old)
+                    .new_price(*new);
+                if let Some(expiry) = plan.expiry {
+                    args.expiry(expiry);
+                }
+                instructions.buy_change_price(args.build()?).await
+            }
+            BidStep::Bid(BidAction::Cancel {
+                token_mint, price, ..
+            }) => {
+                let request = InstructionsBuyCancelRequestArgs::default()
+                    .buyer(buyer)
+                    .auction_house_address(auction_house)
+                    .token_mint(token_mint.as_str())
+                    .price(*price)
+                    .build()?;
+                instructions.buy_cancel(request).await
+            }
+            BidStep::Escrow(EscrowAction::Deposit { amount }) => {
+                let request = InstructionsDepositRequestArgs::default()
+                    .buyer(buyer)
+                    .auction_house_address(auction_house)
+                    .amount(*amount)
+                    .build()?;
+                instructions.deposit(request).await
+            }
+            BidStep::Escrow(EscrowAction::Withdraw { amount }) => {
+                let request = InstructionsWithdrawRequestArgs::default()
+                    .buyer(buyer)
+                    .auction_house_address(auction_house)
+                    .amount(*amount)
+                    .build()?;
+                instructions.withdraw(request).await
+            }
+        }
+    }
+}
+
+fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs() as i64)
+}
@@ -0,0 +1,27 @@
+use std::{error::Error, path::PathBuf};
+
+use magiceden_rs::ClientConfig;
+
+/// Load `path`, or `$XDG_CONFIG_HOME/magiceden/config.toml` or `config.json` (defaulting to `~/.config`)
+/// when one exists. `MAGICEDEN_*` environment variables override the file.
+pub fn load(path: Option<PathBuf>) -> Result<ClientConfig, Box<dyn Error>> {
+    let path = path.or_else(|| {
+        let dir = default_dir()?;
+        ["config.toml", "config.json"]
+            .into_iter()
+            .map(|name| dir.join(name))
+            .find(|path| path.exists())
+    });
+    let config = match path {
+        Some(path) => ClientConfig::from_file(path)?,
+        None => ClientConfig::default(),
+    };
+    Ok(config.with_env()?)
+}
+
+fn default_dir() -> Option<PathBuf> {
+    let config_home = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(config_home.join("magiceden"))
+}
@@ -0,0 +1,599 @@
+//! Command line client for the Magic Eden API, available with the `cli` feature.
+//!
+//! ```text
+//! magiceden collections listings okay_bears --limit 50 --sort listPrice
+//! magiceden mmm pools --owner <wallet> --output table
+//! magiceden instructions buy-now -p buyer=<wallet> -p seller=<wallet> -p price=1.5 ...
+//! ```
+use std::{error::Error, io::Read, path::PathBuf, process::ExitCode};
+
+use clap::{Args, Parser, Subcommand};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{Map, Value};
+
+use magiceden_rs::{types::EvmChain, Client};
+
+mod config;
+mod output;
+
+use output::Format;
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "magiceden",
+    version,
+    about = "Command line client for the Magic Eden API"
+)]
+struct Cli {
+    /// API key, read from the configuration file when not set
+    #[arg(long, env = "MAGICEDEN_API_KEY", hide_env_values = true, global = true)]
+    api_key: Option<String>,
+    /// Base url of the v2 API
+    #[arg(long, env = "MAGICEDEN_API_BASE", global = true)]
+    api_base: Option<String>,
+    /// TOML or JSON client configuration file, e.g. with `api_key` and `api_base`,
+    /// defaults to `~/.config/magiceden/config.toml` or `config.json`
+    #[arg(long, env = "MAGICEDEN_CONFIG", global = true)]
+    config: Option<PathBuf>,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Format::Json, global = true)]
+    output: Format,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Bid manager planning, the manager state is read with `--json`
+    #[command(subcommand)]
+    Bids(BidsCommand),
+    /// Solana collections
+    #[command(subcommand)]
+    Collections(CollectionsCommand),
+    /// EVM chains
+    Evm {
+        /// Chain: ethereum, polygon, base, arbitrum or bsc
+        #[arg(long, default_value = "ethereum", value_parser = parse_chain)]
+        chain: EvmChain,
+        #[command(subcommand)]
+        command: EvmCommand,
+    },
+    /// Instructions to sign and send, request fields are given with `--param` or `--json`
+    #[command(subcommand)]
+    Instructions(InstructionsCommand),
+    /// Launchpad collections
+    #[command(subcommand)]
+    Launchpad(LaunchpadCommand),
+    /// Marketplace wide data
+    #[command(subcommand)]
+    Marketplace(MarketplaceCommand),
+    /// AMM pools
+    #[command(subcommand)]
+    Mmm(MmmCommand),
+    /// Bitcoin Ordinals and Runes
+    #[command(subcommand)]
+    Ordinals(OrdinalsCommand),
+    /// Inventory repricing
+    #[command(subcommand)]
+    Reprice(RepriceCommand),
+    /// Collection sweeps
+    #[command(subcommand)]
+    Sweep(SweepCommand),
+    /// Tokens
+    #[command(subcommand)]
+    Tokens(TokensCommand),
+    /// Wallets
+    #[command(subcommand)]
+    Wallets(WalletsCommand),
+}
+
+/// Request fields shared by every command taking a request
+#[derive(Debug, Args)]
+struct Query {
+    /// Request field as `name=value` using the API field name, e.g. `-p collectionSymbol=okay_bears`.
+    /// Values are parsed as JSON when possible, quote them to force a string: `-p 'tokenId="1"'`
+    #[arg(short, long = "param", value_name = "NAME=VALUE", value_parser = parse_param)]
+    params: Vec<(String, Value)>,
+    /// Read request fields from a JSON file, `-` for stdin
+    #[arg(long, value_name = "PATH")]
+    json: Option<PathBuf>,
+    /// Number of items to skip, for offset paginated endpoints
+    #[arg(long)]
+    offset: Option<i64>,
+    /// Number of items to return
+    #[arg(long)]
+    limit: Option<i64>,
+    /// Continuation token of the previous page, for EVM endpoints
+    #[arg(long)]
+    continuation: Option<String>,
+    /// Sort field, e.g. `listPrice` or `updatedAt` for collection listings
+    #[arg(long)]
+    sort: Option<String>,
+    /// Sort direction, `asc` or `desc`
+    #[arg(long)]
+    sort_direction: Option<String>,
+}
+
+impl Query {
+    /// Build the request from `--json`, then the pagination flags and `--param` on top
+    fn build<T: DeserializeOwned + Serialize>(&self) -> Result<T, Box<dyn Error>> {
+        self.build_with(vec![])
+    }
+
+    /// Same as [`Query::build`] with the command specific `flags` set on top of the pagination flags.
+    /// Fails when a flag is set that the request does not have.
+    fn build_with<T: DeserializeOwned + Serialize>(
+        &self,
+        flags: Vec<(&'static str, Option<Value>)>,
+    ) -> Result<T, Box<dyn Error>> {
+        let mut request = match &self.json {
+            None => Map::new(),
+            Some(path) => {
+                let mut contents = String::new();
+                if path.as_os_str() == "-" {
+                    std::io::stdin().read_to_string(&mut contents)?;
+                } else {
+                    contents = std::fs::read_to_string(path)
+                        .map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+                }
+                match serde_json::from_str(&contents)? {
+                    Value::Object(object) => object,
+                    _ => return Err(format!("{} is not a JSON object", path.display()).into()),
+                }
+            }
+        };
+
+        let pagination = [
+            ("offset", self.offset.map(Value::from)),
+            ("limit", self.limit.map(Value::from)),
+            ("continuation", self.continuation.clone().map(Value::from)),
+            ("sort", self.sort.clone().map(Value::from)),
+            (
+                "sortDirection",
+                self.sort_direction.clone().map(Value::from),
+            ),
+        ];
+        let mut set_flags = vec![];
+        for (name, value) in pagination.into_iter().chain(flags) {
+            if let Some(value) = value {
+                request.insert(name.to_string(), value);
+                set_flags.push(name);
+            }
+        }
+        request.extend(self.params.iter().cloned());
+
+        let request: T = serde_json::from_value(Value::Object(request))?;
+
+        // Fields the request does not have are ignored when deserializing,
+        // a flag missing from the request once serialized again is not supported
+        let fields = serde_json::to_value(&request)?;
+        for name in set_flags {
+            if fields.get(name).is_none() {
+                return Err(format!("--{} is not supported by this command", flag(name)).into());
+            }
+        }
+
+        Ok(request)
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum BidsCommand {
+    /// Plan the bids to place, move or cancel and the escrow top up
+    Plan(Query),
+    /// Plan and fetch the instructions of the plan
+    Instructions(Query),
+}
+
+#[derive(Debug, Subcommand)]
+enum CollectionsCommand {
+    /// Activities of a collection
+    Activities {
+        /// Collection symbol
+        symbol: String,
+        #[command(flatten)]
+        query: Query,
+    },
+    /// Stats of a collection
+    Stats { symbol: String },
+    /// Collections
+    List(Query),
+    /// Listings of a collection, `-p attributes='[[{"traitType":"..","value":".."}]]'` filters by traits
+    Listings {
+        /// Collection symbol
+        symbol: String,
+        #[command(flatten)]
+        query: Query,
+    },
+    /// Holder stats of a collection
+    HolderStats { symbol: String },
+    /// Attributes of a collection with per-trait floor prices
+    Attributes { symbol: String },
+    /// Historical stats of a collection
+    TimeSeries {
+        /// Collection symbol
+        symbol: String,
+        #[command(flatten)]
+        query: Query,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum EvmCommand {
+    /// Collections
+    Collections(Query),
+    /// Tokens with their market data
+    Tokens(Query),
+    /// Asks (listings)
+    Asks(Query),
+    /// Bids (offers)
+    Bids(Query),
+    /// Activity of a user, `-p users=<address>`
+    UserActivity(Query),
+    /// Sales
+    Sales(Query),
+}
+
+#[derive(Debug, Subcommand)]
+enum InstructionsCommand {
+    /// Buy (bid)
+    Buy(Query),
+    /// Buy now
+    BuyNow(Query),
+    /// Buy now and transfer nft to another owner
+    BuyNowTransferNft(Query),
+    /// Cancel a buy
+    BuyCancel(Query),
+    /// Change a buy price
+    BuyChangePrice(Query),
+    /// Sell (list)
+    Sell(Query),
+    /// Change a sell price
+    SellChangePrice(Query),
+    /// Sell now (accept offer)
+    SellNow(Query),
+    /// Cancel a sell
+    SellCancel(Query),
+    /// Deposit to escrow
+    Deposit(Query),
+    /// Withdraw from escrow
+    Withdraw(Query),
+}
+
+#[derive(Debug, Subcommand)]
+enum LaunchpadCommand {
+    /// Launchpad collections with their mint stages
+    Collections(Query),
+}
+
+#[derive(Debug, Subcommand)]
+enum MarketplaceCommand {
+    /// Top popular collections, `-p timeRange=1d`
+    PopularCollections(Query),
+}
+
+#[derive(Debug, Subcommand)]
+enum MmmCommand {
+    /// Pools of an owner or collection
+    Pools {
+        /// Pools created by this wallet
+        #[arg(long)]
+        owner: Option<String>,
+        /// Pools of this collection
+        #[arg(long)]
+        collection_symbol: Option<String>,
+        #[command(flatten)]
+        query: Query,
+    },
+    /// Best offers for an NFT
+    TokenPools {
+        mint_address: String,
+        #[command(flatten)]
+        query: Query,
+    },
+    /// Instruction to create a pool
+    CreatePool(Query),
+    /// Instruction to withdraw sol payment from a pool
+    SolWithdrawBuy(Query),
+    /// Instruction to have a pool fulfill a buy
+    SolFulfillBuy(Query),
+    /// Instruction to have a pool fulfill a sell
+    SolFulfillSell(Query),
+}
+
+#[derive(Debug, Subcommand)]
+enum OrdinalsCommand {
+    /// Tokens (inscriptions), filtered by ids, collection or owner
+    Tokens(Query),
+    /// Activities, filtered by collection, token or owner
+    Activities(Query),
+    /// Collection by symbol
+    Collection { symbol: String },
+    /// Stats of a collection
+    CollectionStats { symbol: String },
+    /// Popular collections, `-p window=1d`
+    PopularCollections(Query),
+    /// Market info of a rune
+    RuneMarketInfo { rune: String },
+    /// Orders of a rune
+    RuneOrders {
+        rune: String,
+        #[command(flatten)]
+        query: Query,
+    },
+    /// Balance of a rune held by a wallet
+    RuneBalance { address: String, rune: String },
+}
+
+#[derive(Debug, Subcommand)]
+enum RepriceCommand {
+    /// Plan the listings to create, reprice or cancel
+    Plan(Query),
+    /// Plan and fetch the instructions of the plan
+    Instructions(Query),
+}
+
+#[derive(Debug, Subcommand)]
+enum SweepCommand {
+    /// Plan the listings to buy within a budget
+    Plan(Query),
+}
+
+#[derive(Debug, Subcommand)]
+enum TokensCommand {
+    /// Listings for a token
+    Listings { token_mint: String },
+    /// Received offers for a token
+    OffersReceived { token_mint: String },
+}
+
+#[derive(Debug, Subcommand)]
+enum WalletsCommand {
+    /// Info about the wallet owner
+    Info { wallet_address: String },
+    /// Escrow balance of a wallet
+    EscrowBalance { wallet_address: String },
+}
+
+/// Flag of the request field `name`, e.g. `--sort-direction` for `sortDirection`
+fn flag(name: &str) -> String {
+    name.chars()
+        .flat_map(|c| match c.is_ascii_uppercase() {
+            true => vec!['-', c.to_ascii_lowercase()],
+            false => vec![c],
+        })
+        .collect()
+}
+
+fn parse_param(param: &str) -> Result<(String, Value), String> {
+    let (name, value) = param
+        .split_once('=')
+        .ok_or_else(|| format!("expected NAME=VALUE, got `{param}`"))?;
+    let value = serde_json::from_str(value).unwrap_or_else(|_| Value::from(value));
+    Ok((name.to_string(), value))
+}
+
+fn parse_chain(chain: &str) -> Result<EvmChain, String> {
+    serde_json::from_value(Value::from(chain.to_lowercase())).map_err(|_| {
+        format!("unknown chain `{chain}`, expected ethereum, polygon, base, arbitrum or bsc")
+    })
+}
+
+fn client(cli: &Cli) -> Result<Client, Box<dyn Error>> {
+    let mut config = config::load(cli.config.clone())?;
+    if let Some(api_key) = cli.api_key.clone() {
+        config.api_key = Some(api_key.into());
+    }
+    if let Some(api_base) = cli.api_base.clone() {
+        config.api_base = Some(api_base);
+    }
+    Ok(config.build()?)
+}
+
+fn json<T: Serialize>(value: T) -> Result<Value, Box<dyn Error>> {
+    Ok(serde_json::to_value(value)?)
+}
+
+async fn run(cli: &Cli) -> Result<Value, Box<dyn Error>> {
+    let client = client(cli)?;
+
+    match &cli.command {
+        Command::Bids(command) => {
+            let bids = client.bids();
+            match command {
+                BidsCommand::Plan(query) => json(bids.plan(&query.build()?).await?),
+                BidsCommand::Instructions(query) => {
+                    let manager = query.build()?;
+                    let plan = bids.plan(&manager).await?;
+                    json(bids.instructions(&manager, &plan).await?)
+                }
+            }
+        }
+        Command::Collections(command) => {
+            let collections = client.collections();
+            match command {
+                CollectionsCommand::Activities { symbol, query } => {
+                    json(collections.activities(symbol, query.build()?).await?)
+                }
+                CollectionsCommand::Stats { symbol } => json(collections.stats(symbol).await?),
+                CollectionsCommand::List(query) => {
+                    json(collections.collections(query.build()?).await?)
+                }
+                CollectionsCommand::Listings { symbol, query } => {
+                    json(collections.listings(symbol, query.build()?).await?)
+                }
+                CollectionsCommand::HolderStats { symbol } => {
+                    json(collections.holder_stats(symbol).await?)
+                }
+                CollectionsCommand::Attributes { symbol } => {
+                    json(collections.attributes(symbol).await?)
+                }
+                CollectionsCommand::TimeSeries { symbol, query } => {
+                    json(collections.time_series(symbol, query.build()?).await?)
+                }
+            }
+        }
+        Command::Evm { chain, command } => {
+            let evm = client.evm(*chain);
+            match command {
+                EvmCommand::Collections(query) => json(evm.collections(query.build()?).await?),
+                EvmCommand::Tokens(query) => json(evm.tokens(query.build()?).await?),
+                EvmCommand::Asks(query) => json(evm.asks(query.build()?).await?),
+                EvmCommand::Bids(query) => json(evm.bids(query.build()?).await?),
+                EvmCommand::UserActivity(query) => json(evm.user_activity(query.build()?).await?),
+                EvmCommand::Sales(query) => json(evm.sales(query.build()?).await?),
+            }
+        }
+        Command::Instructions(command) => {
+            let instructions = client.instructions();
+            json(match command {
+                InstructionsCommand::Buy(query) => instructions.buy(query.build()?).await?,
+                InstructionsCommand::BuyNow(query) => instructions.buy_now(query.build()?).await?,
+                InstructionsCommand::BuyNowTransferNft(query) => {
+                    instructions.buy_now_transfer_nft(query.build()?).await?
+                }
+                InstructionsCommand::BuyCancel(query) => {
+                    instructions.buy_cancel(query.build()?).await?
+                }
+                InstructionsCommand::BuyChangePrice(query) => {
+                    instructions.buy_change_price(query.build()?).await?
+                }
+                InstructionsCommand::Sell(query) => instructions.sell(query.build()?).await?,
+                InstructionsCommand::SellChangePrice(query) => {
+                    instructions.sell_change_price(query.build()?).await?
+                }
+                InstructionsCommand::SellNow(query) => {
+                    instructions.sell_now(query.build()?).await?
+                }
+                InstructionsCommand::SellCancel(query) => {
+                    instructions.sell_cancel(query.build()?).await?
+                }
+                InstructionsCommand::Deposit(query) => instructions.deposit(query.build()?).await?,
+                InstructionsCommand::Withdraw(query) => {
+                    instructions.withdraw(query.build()?).await?
+                }
+            })
+        }
+        Command::Launchpad(LaunchpadCommand::Collections(query)) => {
+            json(client.launchpad().collections(query.build()?).await?)
+        }
+        Command::Marketplace(MarketplaceCommand::PopularCollections(query)) => json(
+            client
+                .marketplace()
+                .popular_collections(query.build()?)
+                .await?,
+        ),
+        Command::Mmm(command) => {
+            let mmm = client.mmm();
+            match command {
+                MmmCommand::Pools {
+                    owner,
+                    collection_symbol,
+                    query,
+                } => {
+                    let request = query.build_with(vec![
+                        ("owner", owner.clone().map(Value::from)),
+                        (
+                            "collectionSymbol",
+                            collection_symbol.clone().map(Value::from),
+                        ),
+                    ])?;
+                    json(mmm.pools(request).await?)
+                }
+                MmmCommand::TokenPools {
+                    mint_address,
+                    query,
+                } => json(mmm.token_pools(mint_address, query.build()?).await?),
+                MmmCommand::CreatePool(query) => json(mmm.create_pool(query.build()?).await?),
+                MmmCommand::SolWithdrawBuy(query) => {
+                    json(mmm.sol_withdraw_buy(query.build()?).await?)
+                }
+                MmmCommand::SolFulfillBuy(query) => {
+                    json(mmm.sol_fulfill_buy(query.build()?).await?)
+                }
+                MmmCommand::SolFulfillSell(query) => {
+                    json(mmm.sol_fulfill_sell(query.build()?).await?)
+                }
+            }
+        }
+        Command::Ordinals(command) => {
+            let ordinals = client.ordinals();
+            match command {
+                OrdinalsCommand::Tokens(query) => json(ordinals.tokens(query.build()?).await?),
+                OrdinalsCommand::Activities(query) => {
+                    json(ordinals.activities(query.build()?).await?)
+                }
+                OrdinalsCommand::Collection { symbol } => json(ordinals.collection(symbol).await?),
+                OrdinalsCommand::CollectionStats { symbol } => {
+                    let request =
+                        serde_json::from_value(serde_json::json!({ "collectionSymbol": symbol }))?;
+                    json(ordinals.collection_stats(request).await?)
+                }
+                OrdinalsCommand::PopularCollections(query) => {
+                    json(ordinals.popular_collections(query.build()?).await?)
+                }
+                OrdinalsCommand::RuneMarketInfo { rune } => {
+                    json(ordinals.rune_market_info(rune).await?)
+                }
+                OrdinalsCommand::RuneOrders { rune, query } => {
+                    json(ordinals.rune_orders(rune, query.build()?).await?)
+                }
+                OrdinalsCommand::RuneBalance { address, rune } => {
+                    json(ordinals.rune_balance(address, rune).await?)
+                }
+            }
+        }
+        Command::Reprice(command) => {
+            let reprice = client.reprice();
+            match command {
+                RepriceCommand::Plan(query) => json(reprice.plan(&query.build()?).await?),
+                RepriceCommand::Instructions(query) => {
+                    let request = query.build()?;
+                    let plan = reprice.plan(&request).await?;
+                    json(reprice.instructions(&request, &plan).await?)
+                }
+            }
+        }
+        Command::Sweep(SweepCommand::Plan(query)) => {
+            json(client.sweep().plan(query.build()?).await?)
+        }
+        Command::Tokens(command) => {
+            let tokens = client.tokens();
+            match command {
+                TokensCommand::Listings { token_mint } => json(tokens.listings(token_mint).await?),
+                TokensCommand::OffersReceived { token_mint } => {
+                    json(tokens.offers_received(token_mint).await?)
+                }
+            }
+        }
+        Command::Wallets(command) => {
+            let wallets = client.wallets();
+            match command {
+                WalletsCommand::Info { wallet_address } => {
+                    json(wallets.info(wallet_address).await?)
+                }
+                WalletsCommand::EscrowBalance { wallet_address } => {
+                    json(wallets.escrow_balance(wallet_address).await?)
+                }
+            }
+        }
+    }
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match run(&cli).await {
+        Ok(value) => output::render(&value, cli.output, &mut std::io::stdout().lock())
+            .map_err(Box::<dyn Error>::from),
+        Err(e) => Err(e),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use std::io::{self, Write};
+
+use clap::ValueEnum;
+use serde_json::{Map, Value};
+
+/// Widest table cell before truncation
+const MAX_CELL_WIDTH: usize = 44;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Pretty printed JSON as returned by the API
+    Json,
+    /// Aligned columns, long cells are truncated
+    Table,
+    /// Comma separated values with a header row
+    Csv,
+}
+
+pub fn render<W: Write>(value: &Value, format: Format, out: &mut W) -> io::Result<()> {
+    match format {
+        Format::Json => {
+            serde_json::to_writer_pretty(&mut *out, value)?;
+            writeln!(out)
+        }
+        Format::Table => {
+            let (columns, rows) = table(value);
+            let widths: Vec<usize> = columns
+                .iter()
+                .enumerate()
+                .map(|(i, column)| {
+                    rows.iter()
+                        .map(|row| row[i].chars().count())
+                        .chain([column.chars().count()])
+                        .max()
+                        .unwrap_or_default()
+                        .min(MAX_CELL_WIDTH)
+                })
+                .collect();
+
+            for row in [columns].iter().chain(&rows) {
+                let cells: Vec<String> = row
+                    .iter()
+                    .zip(&widths)
+                    .map(|(cell, width)| format!("{:width$}", truncate(cell, *width)))
+                    .collect();
+                writeln!(out, "{}", cells.join("  ").trim_end())?;
+            }
+            Ok(())
+        }
+        Format::Csv => {
+            let (columns, rows) = table(value);
+            let mut writer = csv::Writer::from_writer(out);
+            writer.write_record(&columns)?;
+            for row in rows {
+                writer.write_record(&row)?;
+            }
+            writer.flush()
+        }
+    }
+}
+
+/// Columns and rows of a response.
+///
+/// Arrays are one row per element, objects wrapping a single array (e.g. `{"orders": [..], "continuation": ..}`)
+/// are one row per element of that array and any other object is a single row.
+/// Nested objects are flattened into `parent.child` columns, nested arrays are kept as JSON.
+fn table(value: &Value) -> (Vec<String>, Vec<Vec<String>>) {
+    let items: Vec<&Value> = match value {
+        Value::Array(items) => items.iter().collect(),
+        Value::Object(object) => {
+            let mut arrays = object.values().filter_map(|value| match value {
+                Value::Array(items) if items.iter().all(Value::is_object) => Some(items),
+                _ => None,
+            });
+            match (arrays.next(), arrays.next()) {
+                (Some(items), None) => items.iter().collect(),
+                _ => vec![value],
+            }
+        }
+        _ => vec![value],
+    };
+
+    let rows: Vec<Map<String, Value>> = items
+        .into_iter()
+        .map(|item| {
+            let mut row = Map::new();
+            match item {
+                Value::Object(object) => flatten("", object, &mut row),
+                other => {
+                    row.insert("value".to_string(), other.clone());
+                }
+            }
+            row
+        })
+        .collect();
+
+    let mut columns: Vec<String> = vec![];
+    for row in &rows {
+        for key in row.keys() {
+            if !columns.contains(key) {
+                columns.push(key.clone());
+            }
+        }
+    }
+
+    let rows = rows
+        .iter()
+        .map(|row| {
+            columns
+                .iter()
+                .map(|column| row.get(column).map(cell).unwrap_or_default())
+                .collect()
+        })
+        .collect();
+    (columns, rows)
+}
+
+fn flatten(prefix: &str, object: &Map<String, Value>, row: &mut Map<String, Value>) {
+    for (key, value) in object {
+        let key = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{prefix}.{key}")
+        };
+        match value {
+            Value::Object(object) => flatten(&key, object, row),
+            value => {
+                row.insert(key, value.clone());
+            }
+        }
+    }
+}
+
+fn cell(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(value) => value.clone(),
+        value => value.to_string(),
+    }
+}
+
+fn truncate(cell: &str, width: usize) -> String {
+    if cell.chars().count() <= width {
+        return cell.to_string();
+    }
+    let mut cell: String = cell.chars().take(width.saturating_sub(1)).collect();
+    cell.push('…');
+    cell
+}
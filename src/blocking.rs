@@ -0,0 +1,471 @@
+//! Blocking client, available with the `blocking` feature.
+//!
+//! Every API group of the async [`Client`](crate::Client) is mirrored with the same
+//! requests and responses; calls are driven to completion on a runtime owned by the client.
+//! The blocking client must not be created or dropped from within an async context.
+use std::{future::Future, pin::Pin, sync::Arc, time::Duration};
+
+use futures::{Stream, StreamExt};
+use tokio::runtime::Runtime;
+
+use crate::{
+    error::MagicedenError,
+    types::{
+        BidInstruction, BidManager, BidPlan, CollectionActivitiesRequest,
+        CollectionActivitiesResponse, CollectionActivitiy, CollectionAttributesResponse,
+        CollectionListingsRequest, CollectionListingsResponse, CollectionStatsResponse,
+        CollectionTimeSeriesRequest, CollectionTimeSeriesResponse, CollectionsRequest,
+        CollectionsResponse, EvmChain, EvmCollectionsRequest, EvmCollectionsResponse,
+        EvmOrdersRequest, EvmOrdersResponse, EvmSalesRequest, EvmSalesResponse, EvmTokensRequest,
+        EvmTokensResponse, EvmUserActivityRequest, EvmUserActivityResponse, HolderStatsResponse,
+        InstructionResponse, InstructionsBuyCancelRequest, InstructionsBuyChangePriceRequest,
+        InstructionsBuyNowRequest, InstructionsBuyNowTransferNftRequest, InstructionsBuyRequest,
+        InstructionsDepositRequest, InstructionsSellCancelRequest,
+        InstructionsSellChangePriceRequest, InstructionsSellNowRequest, InstructionsSellRequest,
+        InstructionsWithdrawRequest, LaunchpadCollectionsRequest, LaunchpadCollectionsResponse,
+        ListingEvent, MmmCreatePoolRequest, MmmPoolsRequest, MmmPoolsResponse,
+        MmmSolFulfillBuyRequest, MmmSolFulfillSellRequest, MmmSolWithdrawBuyRequest,
+        MmmTokenPoolsRequest, MmmTokenPoolsResponse, OrdinalsActivitiesRequest,
+        OrdinalsActivitiesResponse, OrdinalsCollection, OrdinalsCollectionStatsRequest,
+        OrdinalsCollectionStatsResponse, OrdinalsPopularCollectionsRequest,
+        OrdinalsPopularCollectionsResponse, OrdinalsTokensRequest, OrdinalsTokensResponse,
+        PopularCollectionsRequest, PopularCollectionsResponse, RepriceInstruction, RepricePlan,
+        RepriceRequest, RequestOptions, RuneBalanceResponse, RuneMarketInfoResponse,
+        RuneOrdersRequest, RuneOrdersResponse, SweepPlan, SweepRequest, TokenListingsResponse,
+        TokenOffersReceivedResponse, WalletEscrowBalanceResponse, WalletInfoResponse,
+    },
+    ApiKey, ApiKeys, Auth, Cluster,
+};
+
+#[derive(Debug, Clone)]
+pub struct Client {
+    inner: crate::Client,
+    runtime: Arc<Runtime>,
+}
+
+impl TryFrom<crate::Client> for Client {
+    type Error = MagicedenError;
+
+    /// Wrap an async client, failing when the runtime cannot be created
+    fn try_from(inner: crate::Client) -> Result<Self, Self::Error> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(MagicedenError::Runtime)?;
+        Ok(Self {
+            inner,
+            runtime: Arc::new(runtime),
+        })
+    }
+}
+
+impl Client {
+    pub fn new() -> Result<Self, MagicedenError> {
+        crate::Client::new().try_into()
+    }
+
+    pub fn with_http_client(self, http_client: reqwest::Client) -> Self {
+        self.map(|inner| inner.with_http_client(http_client))
+    }
+
+    /// See [`crate::Client::from_env`]
+    pub fn from_env() -> Result<Self, MagicedenError> {
+        crate::Client::from_env()?.try_into()
+    }
+
+    pub fn with_api_key<S: Into<ApiKey>>(self, api_key: S) -> Result<Self, MagicedenError> {
+        Ok(Self {
+            inner: self.inner.with_api_key(api_key)?,
+            runtime: self.runtime,
+        })
+    }
+
+    pub fn with_api_keys(self, api_keys: ApiKeys) -> Self {
+        self.map(|inner| inner.with_api_keys(api_keys))
+    }
+
+    pub fn with_auth(self, auth: Auth) -> Self {
+        self.map(|inner| inner.with_auth(auth))
+    }
+
+    pub fn with_api_base<S: Into<String>>(self, api_base: S) -> Self {
+        self.map(|inner| inner.with_api_base(api_base))
+    }
+
+    pub fn with_cluster(self, cluster: Cluster) -> Self {
+        self.map(|inner| inner.with_cluster(cluster))
+    }
+
+    pub fn with_auction_house_address<S: Into<String>>(self, auction_house_address: S) -> Self {
+        self.map(|inner| inner.with_auction_house_address(auction_house_address))
+    }
+
+    pub fn with_ordinals_api_base<S: Into<String>>(self, ordinals_api_base: S) -> Self {
+        self.map(|inner| inner.with_ordinals_api_base(ordinals_api_base))
+    }
+
+    pub fn with_evm_api_base<S: Into<String>>(self, evm_api_base: S) -> Self {
+        self.map(|inner| inner.with_evm_api_base(evm_api_base))
+    }
+
+    pub fn with_backoff(self, backoff: backoff::ExponentialBackoff) -> Self {
+        self.map(|inner| inner.with_backoff(backoff))
+    }
+
+    /// The async client the requests are delegated to
+    pub fn inner(&self) -> &crate::Client {
+        &self.inner
+    }
+
+    pub fn bids(&self) -> Bids<'_> {
+        Bids::new(self)
+    }
+
+    pub fn instructions(&self) -> Instructions<'_> {
+        Instructions::new(self)
+    }
+
+    pub fn launchpad(&self) -> Launchpad<'_> {
+        Launchpad::new(self)
+    }
+
+    pub fn collections(&self) -> Collections<'_> {
+        Collections::new(self)
+    }
+
+    pub fn marketplace(&self) -> Marketplace<'_> {
+        Marketplace::new(self)
+    }
+
+    pub fn mmm(&self) -> Mmm<'_> {
+        Mmm::new(self)
+    }
+
+    pub fn reprice(&self) -> Reprice<'_> {
+        Reprice::new(self)
+    }
+
+    pub fn sweep(&self) -> Sweep<'_> {
+        Sweep::new(self)
+    }
+
+    pub fn tokens(&self) -> Tokens<'_> {
+        Tokens::new(self)
+    }
+
+    pub fn wallets(&self) -> Wallets<'_> {
+        Wallets::new(self)
+    }
+
+    pub fn ordinals(&self) -> Ordinals<'_> {
+        Ordinals::new(self)
+    }
+
+    pub fn evm(&self, chain: EvmChain) -> Evm<'_> {
+        Evm {
+            client: self,
+            inner: self.inner.evm(chain),
+        }
+    }
+
+    fn map(self, f: impl FnOnce(crate::Client) -> crate::Client) -> Self {
+        Self {
+            inner: f(self.inner),
+            runtime: self.runtime,
+        }
+    }
+
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+}
+
+/// Blocking iterator over a stream of the async client
+pub struct Iter<'c, T> {
+    client: &'c Client,
+    stream: Pin<Box<dyn Stream<Item = Result<T, MagicedenError>> + 'c>>,
+}
+
+impl<T> Iterator for Iter<'_, T> {
+    type Item = Result<T, MagicedenError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.client.block_on(self.stream.next())
+    }
+}
+
+/// Declare a blocking group delegating each method to the async group of the same name
+macro_rules! group {
+    ($group:ident { $($(#[$meta:meta])* fn $name:ident(&self $(, $arg:ident: $ty:ty)*) -> $output:ty;)* }) => {
+        pub struct $group<'c> {
+            client: &'c Client,
+            inner: crate::$group<'c>,
+        }
+
+        impl<'c> $group<'c> {
+            $(
+                $(#[$meta])*
+                pub fn $name(&self $(, $arg: $ty)*) -> Result<$output, MagicedenError> {
+                    self.client.block_on(self.inner.$name($($arg),*))
+                }
+            )*
+        }
+    };
+    ($group:ident::new { $($body:tt)* }) => {
+        group!($group { $($body)* });
+
+        impl<'c> $group<'c> {
+            pub fn new(client: &'c Client) -> Self {
+                Self {
+                    client,
+                    inner: crate::$group::new(&client.inner),
+                }
+            }
+        }
+    };
+}
+
+group!(Bids::new {
+    /// See [`crate::Bids::plan`]
+    fn plan(&self, manager: &BidManager) -> BidPlan;
+    /// See [`crate::Bids::instructions`]
+    fn instructions(&self, manager: &BidManager, plan: &BidPlan) -> Vec<BidInstruction>;
+});
+
+group!(Collections::new {
+    /// Get activities of a collection
+    fn activities(&self, symbol: &str, request: CollectionActivitiesRequest) -> CollectionActivitiesResponse;
+    /// See [`crate::Collections::activities_with_options`]
+    fn activities_with_options(&self, symbol: &str, request: CollectionActivitiesRequest, options: &RequestOptions) -> CollectionActivitiesResponse;
+    /// Get stats of a collection
+    fn stats(&self, symbol: &str) -> CollectionStatsResponse;
+    /// See [`crate::Collections::stats_with_options`]
+    fn stats_with_options(&self, symbol: &str, options: &RequestOptions) -> CollectionStatsResponse;
+    /// Get collections
+    fn collections(&self, request: CollectionsRequest) -> CollectionsResponse;
+    /// See [`crate::Collections::collections_with_options`]
+    fn collections_with_options(&self, request: CollectionsRequest, options: &RequestOptions) -> CollectionsResponse;
+    /// Get listings of a collection
+    fn listings(&self, symbol: &str, request: CollectionListingsRequest) -> CollectionListingsResponse;
+    /// See [`crate::Collections::listings_with_options`]
+    fn listings_with_options(&self, symbol: &str, request: CollectionListingsRequest, options: &RequestOptions) -> CollectionListingsResponse;
+    /// Get holder stats of a collection
+    fn holder_stats(&self, symbol: &str) -> HolderStatsResponse;
+    /// See [`crate::Collections::holder_stats_with_options`]
+    fn holder_stats_with_options(&self, symbol: &str, options: &RequestOptions) -> HolderStatsResponse;
+    /// Get attributes of a collection with listed counts and per-trait floor prices
+    fn attributes(&self, symbol: &str) -> CollectionAttributesResponse;
+    /// See [`crate::Collections::attributes_with_options`]
+    fn attributes_with_options(&self, symbol: &str, options: &RequestOptions) -> CollectionAttributesResponse;
+    /// Get historical stats of a collection
+    fn time_series(&self, symbol: &str, request: CollectionTimeSeriesRequest) -> CollectionTimeSeriesResponse;
+    /// See [`crate::Collections::time_series_with_options`]
+    fn time_series_with_options(&self, symbol: &str, request: CollectionTimeSeriesRequest, options: &RequestOptions) -> CollectionTimeSeriesResponse;
+});
+
+impl<'c> Collections<'c> {
+    /// See [`crate::Collections::watch_activities`]
+    pub fn watch_activities(
+        &self,
+        symbol: &str,
+        interval: Duration,
+    ) -> Iter<'c, CollectionActivitiy> {
+        Iter {
+            client: self.client,
+            stream: Box::pin(self.inner.watch_activities(symbol, interval)),
+        }
+    }
+
+    /// See [`crate::Collections::watch_listings`]
+    pub fn watch_listings(
+        &self,
+        symbol: &str,
+        request: CollectionListingsRequest,
+        interval: Duration,
+    ) -> Iter<'c, ListingEvent> {
+        Iter {
+            client: self.client,
+            stream: Box::pin(self.inner.watch_listings(symbol, request, interval)),
+        }
+    }
+
+    /// See [`crate::Collections::subscribe_activities`]
+    pub fn subscribe_activities(
+        &self,
+        symbol: &str,
+    ) -> Result<Iter<'c, CollectionActivitiy>, MagicedenError> {
+        Ok(Iter {
+            client: self.client,
+            stream: self
+                .client
+                .block_on(self.inner.subscribe_activities(symbol))?,
+        })
+    }
+
+    /// See [`crate::Collections::subscribe_listings`]
+    pub fn subscribe_listings(
+        &self,
+        symbol: &str,
+    ) -> Result<Iter<'c, ListingEvent>, MagicedenError> {
+        Ok(Iter {
+            client: self.client,
+            stream: self
+                .client
+                .block_on(self.inner.subscribe_listings(symbol))?,
+        })
+    }
+}
+
+group!(Evm {
+    /// Get collections
+    fn collections(&self, request: EvmCollectionsRequest) -> EvmCollectionsResponse;
+    /// Get tokens with their market data
+    fn tokens(&self, request: EvmTokensRequest) -> EvmTokensResponse;
+    /// Get asks (listings)
+    fn asks(&self, request: EvmOrdersRequest) -> EvmOrdersResponse;
+    /// Get bids (offers)
+    fn bids(&self, request: EvmOrdersRequest) -> EvmOrdersResponse;
+    /// Get activity of a user
+    fn user_activity(&self, request: EvmUserActivityRequest) -> EvmUserActivityResponse;
+    /// Get sales
+    fn sales(&self, request: EvmSalesRequest) -> EvmSalesResponse;
+});
+
+group!(Instructions::new {
+    /// Get instruction to buy (bid)
+    fn buy(&self, request: InstructionsBuyRequest) -> InstructionResponse;
+    /// See [`crate::Instructions::buy_with_options`]
+    fn buy_with_options(&self, request: InstructionsBuyRequest, options: &RequestOptions) -> InstructionResponse;
+    /// Get instruction to buy now
+    fn buy_now(&self, request: InstructionsBuyNowRequest) -> InstructionResponse;
+    /// See [`crate::Instructions::buy_now_with_options`]
+    fn buy_now_with_options(&self, request: InstructionsBuyNowRequest, options: &RequestOptions) -> InstructionResponse;
+    /// Get instruction to buy now and transfer nft to another owner
+    fn buy_now_transfer_nft(&self, request: InstructionsBuyNowTransferNftRequest) -> InstructionResponse;
+    /// See [`crate::Instructions::buy_now_transfer_nft_with_options`]
+    fn buy_now_transfer_nft_with_options(&self, request: InstructionsBuyNowTransferNftRequest, options: &RequestOptions) -> InstructionResponse;
+    /// Get instruction to cancel a buy
+    fn buy_cancel(&self, request: InstructionsBuyCancelRequest) -> InstructionResponse;
+    /// See [`crate::Instructions::buy_cancel_with_options`]
+    fn buy_cancel_with_options(&self, request: InstructionsBuyCancelRequest, options: &RequestOptions) -> InstructionResponse;
+    /// Get instruction to change a buy price
+    fn buy_change_price(&self, request: InstructionsBuyChangePriceRequest) -> InstructionResponse;
+    /// See [`crate::Instructions::buy_change_price_with_options`]
+    fn buy_change_price_with_options(&self, request: InstructionsBuyChangePriceRequest, options: &RequestOptions) -> InstructionResponse;
+    /// Get instruction to sell (list)
+    fn sell(&self, request: InstructionsSellRequest) -> InstructionResponse;
+    /// See [`crate::Instructions::sell_with_options`]
+    fn sell_with_options(&self, request: InstructionsSellRequest, options: &RequestOptions) -> InstructionResponse;
+    /// Get instruction to change a sell price
+    fn sell_change_price(&self, request: InstructionsSellChangePriceRequest) -> InstructionResponse;
+    /// See [`crate::Instructions::sell_change_price_with_options`]
+    fn sell_change_price_with_options(&self, request: InstructionsSellChangePriceRequest, options: &RequestOptions) -> InstructionResponse;
+    /// Get instruction to sell now (accept offer)
+    fn sell_now(&self, request: InstructionsSellNowRequest) -> InstructionResponse;
+    /// See [`crate::Instructions::sell_now_with_options`]
+    fn sell_now_with_options(&self, request: InstructionsSellNowRequest, options: &RequestOptions) -> InstructionResponse;
+    /// Get instruction to cancel a sell
+    fn sell_cancel(&self, request: InstructionsSellCancelRequest) -> InstructionResponse;
+    /// See [`crate::Instructions::sell_cancel_with_options`]
+    fn sell_cancel_with_options(&self, request: InstructionsSellCancelRequest, options: &RequestOptions) -> InstructionResponse;
+    /// Get instruction to deposit to escrow
+    fn deposit(&self, request: InstructionsDepositRequest) -> InstructionResponse;
+    /// See [`crate::Instructions::deposit_with_options`]
+    fn deposit_with_options(&self, request: InstructionsDepositRequest, options: &RequestOptions) -> InstructionResponse;
+    /// Get instruction to withdraw from escrow
+    fn withdraw(&self, request: InstructionsWithdrawRequest) -> InstructionResponse;
+    /// See [`crate::Instructions::withdraw_with_options`]
+    fn withdraw_with_options(&self, request: InstructionsWithdrawRequest, options: &RequestOptions) -> InstructionResponse;
+});
+
+group!(Launchpad::new {
+    /// Get launchpad collections with their mint stages
+    fn collections(&self, request: LaunchpadCollectionsRequest) -> LaunchpadCollectionsResponse;
+});
+
+group!(Marketplace::new {
+    /// Get the top 50 popular collections for a time range
+    fn popular_collections(&self, request: PopularCollectionsRequest) -> PopularCollectionsResponse;
+    /// See [`crate::Marketplace::popular_collections_with_options`]
+    fn popular_collections_with_options(&self, request: PopularCollectionsRequest, options: &RequestOptions) -> PopularCollectionsResponse;
+});
+
+group!(Mmm::new {
+    /// Get AMM pools corresponding to an owner or collection symbol. At least one of required collectionSymbol or owner is required!
+    fn pools(&self, request: MmmPoolsRequest) -> MmmPoolsResponse;
+    /// See [`crate::Mmm::pools_with_options`]
+    fn pools_with_options(&self, request: MmmPoolsRequest, options: &RequestOptions) -> MmmPoolsResponse;
+    /// Get best offers for an NFT
+    fn token_pools(&self, mint_address: &str, request: MmmTokenPoolsRequest) -> MmmTokenPoolsResponse;
+    /// See [`crate::Mmm::token_pools_with_options`]
+    fn token_pools_with_options(&self, mint_address: &str, request: MmmTokenPoolsRequest, options: &RequestOptions) -> MmmTokenPoolsResponse;
+    /// Get instruction to create a pool
+    fn create_pool(&self, request: MmmCreatePoolRequest) -> InstructionResponse;
+    /// See [`crate::Mmm::create_pool_with_options`]
+    fn create_pool_with_options(&self, request: MmmCreatePoolRequest, options: &RequestOptions) -> InstructionResponse;
+    /// Get instruction to withdraw sol payment from a pool
+    fn sol_withdraw_buy(&self, request: MmmSolWithdrawBuyRequest) -> InstructionResponse;
+    /// See [`crate::Mmm::sol_withdraw_buy_with_options`]
+    fn sol_withdraw_buy_with_options(&self, request: MmmSolWithdrawBuyRequest, options: &RequestOptions) -> InstructionResponse;
+    /// Get instruction to have a pool fulfill a buy
+    fn sol_fulfill_buy(&self, request: MmmSolFulfillBuyRequest) -> InstructionResponse;
+    /// See [`crate::Mmm::sol_fulfill_buy_with_options`]
+    fn sol_fulfill_buy_with_options(&self, request: MmmSolFulfillBuyRequest, options: &RequestOptions) -> InstructionResponse;
+    /// Get instruction to have a pool fulfill a sell
+    fn sol_fulfill_sell(&self, request: MmmSolFulfillSellRequest) -> InstructionResponse;
+    /// See [`crate::Mmm::sol_fulfill_sell_with_options`]
+    fn sol_fulfill_sell_with_options(&self, request: MmmSolFulfillSellRequest, options: &RequestOptions) -> InstructionResponse;
+});
+
+group!(Ordinals::new {
+    /// Get tokens (inscriptions), filtered by ids, collection or owner
+    fn tokens(&self, request: OrdinalsTokensRequest) -> OrdinalsTokensResponse;
+    /// Get activities, filtered by collection, token or owner
+    fn activities(&self, request: OrdinalsActivitiesRequest) -> OrdinalsActivitiesResponse;
+    /// Get a collection by symbol
+    fn collection(&self, symbol: &str) -> OrdinalsCollection;
+    /// Get stats of a collection
+    fn collection_stats(&self, request: OrdinalsCollectionStatsRequest) -> OrdinalsCollectionStatsResponse;
+    /// Get popular collections for a time window
+    fn popular_collections(&self, request: OrdinalsPopularCollectionsRequest) -> OrdinalsPopularCollectionsResponse;
+    /// Get market info of a rune
+    fn rune_market_info(&self, rune: &str) -> RuneMarketInfoResponse;
+    /// Get orders of a rune
+    fn rune_orders(&self, rune: &str, request: RuneOrdersRequest) -> RuneOrdersResponse;
+    /// Get the balance of a rune held by a wallet
+    fn rune_balance(&self, address: &str, rune: &str) -> RuneBalanceResponse;
+});
+
+group!(Reprice::new {
+    /// See [`crate::Reprice::plan`]
+    fn plan(&self, request: &RepriceRequest) -> RepricePlan;
+    /// See [`crate::Reprice::instructions`]
+    fn instructions(&self, request: &RepriceRequest, plan: &RepricePlan) -> Vec<RepriceInstruction>;
+});
+
+group!(Sweep::new {
+    /// See [`crate::Sweep::plan`]
+    fn plan(&self, request: SweepRequest) -> SweepPlan;
+});
+
+group!(Tokens::new {
+    /// Get listings for a token
+    fn listings(&self, token_mint: &str) -> TokenListingsResponse;
+    /// See [`crate::Tokens::listings_with_options`]
+    fn listings_with_options(&self, token_mint: &str, options: &RequestOptions) -> TokenListingsResponse;
+    /// Get received offers for a token
+    fn offers_received(&self, token_mint: &str) -> TokenOffersReceivedResponse;
+    /// See [`crate::Tokens::offers_received_with_options`]
+    fn offers_received_with_options(&self, token_mint: &str, options: &RequestOptions) -> TokenOffersReceivedResponse;
+});
+
+group!(Wallets::new {
+    /// Get info about the wallet owner.
+    fn info(&self, wallet_address: &str) -> WalletInfoResponse;
+    /// See [`crate::Wallets::info_with_options`]
+    fn info_with_options(&self, wallet_address: &str, options: &RequestOptions) -> WalletInfoResponse;
+    /// Get escrow balance of a wallet.
+    fn escrow_balance(&self, wallet_address: &str) -> WalletEscrowBalanceResponse;
+    /// See [`crate::Wallets::escrow_balance_with_options`]
+    fn escrow_balance_with_options(&self, wallet_address: &str, options: &RequestOptions) -> WalletEscrowBalanceResponse;
+});
@@ -1,15 +1,34 @@
+use std::{
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
 use bytes::Bytes;
+use futures::{Stream, StreamExt};
+use reqwest_eventsource::{Event, EventSource};
 use serde::{de::DeserializeOwned, Serialize};
+use tracing::Instrument;
 
 use crate::{
     error::{map_deserialization_error, MagicedenError, WrappedError},
-    Collections, Instructions, Marketplace, Mmm, Tokens, Wallets,
+    metrics,
+    types::{EvmChain, RequestOptions},
+    ApiKey, ApiKeys, Auth, Bids, ClientConfig, Cluster, Collections, Evm, Instructions, Launchpad,
+    Marketplace, Mmm, Ordinals, Reprice, Sweep, Tokens, Wallets,
 };
 
 #[derive(Debug, Clone)]
 pub struct Client {
-    api_key: String,
+    api_keys: Arc<ApiKeys>,
+    auth: Auth,
     api_base: String,
+    auction_house_address: Option<String>,
+    ordinals_api_base: String,
+    evm_api_base: String,
     http_client: reqwest::Client,
     backoff: backoff::ExponentialBackoff,
 }
@@ -17,11 +36,57 @@ pub struct Client {
 /// Default v2 API base url
 pub const API_BASE: &str = "https://api-mainnet.magiceden.dev/v2";
 
+/// Default Bitcoin Ordinals API base url
+pub const ORDINALS_API_BASE: &str = "https://api-mainnet.magiceden.dev/v2/ord/btc";
+
+/// Default EVM v3 RTP API base url, the chain is appended as a path segment
+pub const EVM_API_BASE: &str = "https://api-mainnet.magiceden.dev/v3/rtp";
+
+/// Environment variable holding the API key read by [`Client::from_env`]
+pub const API_KEY_ENV: &str = "MAGICEDEN_API_KEY";
+
+/// Environment variable holding the path of a file with the API key read by [`Client::from_env`]
+pub const API_KEY_FILE_ENV: &str = "MAGICEDEN_API_KEY_FILE";
+
+/// Events of a subscription buffered until the consumer reads them
+const STREAM_BUFFER: usize = 64;
+
+/// Path of a request along with the endpoint it was built from, e.g. `/collections/{symbol}/stats`
+#[derive(Debug, Clone)]
+pub(crate) struct Path {
+    pub(crate) endpoint: &'static str,
+    pub(crate) path: String,
+}
+
+impl From<&'static str> for Path {
+    fn from(path: &'static str) -> Self {
+        Self {
+            endpoint: path,
+            path: path.to_string(),
+        }
+    }
+}
+
+/// Build a [`Path`] from an endpoint, formatting its `{param}`s from the variables in scope
+macro_rules! endpoint {
+    ($endpoint:literal) => {
+        $crate::client::Path {
+            endpoint: $endpoint,
+            path: format!($endpoint),
+        }
+    };
+}
+pub(crate) use endpoint;
+
 impl Default for Client {
     fn default() -> Self {
         Self {
-            api_key: "".to_string(),
+            api_keys: Default::default(),
+            auth: Auth::default(),
             api_base: API_BASE.to_string(),
+            auction_house_address: None,
+            ordinals_api_base: ORDINALS_API_BASE.to_string(),
+            evm_api_base: EVM_API_BASE.to_string(),
             http_client: reqwest::Client::new(),
             backoff: backoff::ExponentialBackoff::default(),
         }
@@ -39,8 +104,35 @@ impl Client {
         self
     }
 
-    pub fn with_api_key<S: Into<String>>(mut self, api_key: S) -> Self {
-        self.api_key = api_key.into();
+    /// Create a client configured by the `MAGICEDEN_*` environment variables, e.g. authenticated with
+    /// the key of `MAGICEDEN_API_KEY` or read from the file at `MAGICEDEN_API_KEY_FILE`.
+    /// See [`ClientConfig::with_env`] for every variable.
+    pub fn from_env() -> Result<Self, MagicedenError> {
+        ClientConfig::from_env()?.build()
+    }
+
+    /// Use a single API key, replacing any key pool. An empty key removes the keys.
+    ///
+    /// Fails when the key contains characters not allowed in a header.
+    pub fn with_api_key<S: Into<ApiKey>>(mut self, api_key: S) -> Result<Self, MagicedenError> {
+        let api_key = api_key.into();
+        self.api_keys = Arc::new(if api_key.expose_secret().is_empty() {
+            ApiKeys::default()
+        } else {
+            ApiKeys::new([api_key])?
+        });
+        Ok(self)
+    }
+
+    /// Spread requests over a pool of API keys, see [`ApiKeys`]
+    pub fn with_api_keys(mut self, api_keys: ApiKeys) -> Self {
+        self.api_keys = Arc::new(api_keys);
+        self
+    }
+
+    /// How the API keys are sent, bearer token by default
+    pub fn with_auth(mut self, auth: Auth) -> Self {
+        self.auth = auth;
         self
     }
 
@@ -49,6 +141,27 @@ impl Client {
         self
     }
 
+    /// Use the v2 API of `cluster`, e.g. [`Cluster::Devnet`] to keep test trades off mainnet
+    pub fn with_cluster(self, cluster: Cluster) -> Self {
+        self.with_api_base(cluster.api_base())
+    }
+
+    /// Auction house filled in instruction requests without one, required by clusters other than mainnet
+    pub fn with_auction_house_address<S: Into<String>>(mut self, auction_house_address: S) -> Self {
+        self.auction_house_address = Some(auction_house_address.into());
+        self
+    }
+
+    pub fn with_ordinals_api_base<S: Into<String>>(mut self, ordinals_api_base: S) -> Self {
+        self.ordinals_api_base = ordinals_api_base.into();
+        self
+    }
+
+    pub fn with_evm_api_base<S: Into<String>>(mut self, evm_api_base: S) -> Self {
+        self.evm_api_base = evm_api_base.into();
+        self
+    }
+
     pub fn with_backoff(mut self, backoff: backoff::ExponentialBackoff) -> Self {
         self.backoff = backoff;
         self
@@ -58,12 +171,47 @@ impl Client {
         &self.api_base
     }
 
-    pub fn api_key(&self) -> &str {
-        &self.api_key
+    /// The cluster served at the v2 API base
+    pub fn cluster(&self) -> Cluster {
+        Cluster::from_api_base(&self.api_base)
     }
 
-    fn url(&self, path: &str) -> String {
-        format!("{}{path}", self.api_base)
+    /// Auction house filled in instruction requests without one: the one set on the client,
+    /// or the default of the cluster
+    pub fn auction_house_address(&self) -> Option<&str> {
+        self.auction_house_address
+            .as_deref()
+            .or_else(|| self.cluster().auction_house_address())
+    }
+
+    pub fn ordinals_api_base(&self) -> &str {
+        &self.ordinals_api_base
+    }
+
+    pub fn evm_api_base(&self) -> &str {
+        &self.evm_api_base
+    }
+
+    pub fn backoff(&self) -> &backoff::ExponentialBackoff {
+        &self.backoff
+    }
+
+    /// The first API key of the pool
+    pub fn api_key(&self) -> Option<&ApiKey> {
+        (!self.api_keys.is_empty()).then(|| self.api_keys.key(0))
+    }
+
+    /// The API keys with their usage
+    pub fn api_keys(&self) -> &ApiKeys {
+        &self.api_keys
+    }
+
+    pub fn auth(&self) -> &Auth {
+        &self.auth
+    }
+
+    fn url(&self, base: &str, path: &str) -> String {
+        format!("{base}{path}")
     }
 
     fn query(&self) -> Vec<(&str, &str)> {
@@ -71,158 +219,452 @@ impl Client {
     }
 
     fn headers(&self) -> reqwest::header::HeaderMap {
-        let mut headers = reqwest::header::HeaderMap::new();
-        headers.insert(
-            reqwest::header::AUTHORIZATION,
-            reqwest::header::HeaderValue::from_str(&format!("Bearer {}", self.api_key)).unwrap(),
-        );
+        reqwest::header::HeaderMap::new()
+    }
 
-        headers
+    /// Header authenticating with the key at `index` of the pool, none without keys or with [`Auth::None`]
+    fn authorization(
+        &self,
+        index: Option<usize>,
+    ) -> Option<(reqwest::header::HeaderName, reqwest::header::HeaderValue)> {
+        self.auth.header(self.api_keys.key(index?))
     }
 
     // API groups
 
-    pub fn instructions(&self) -> Instructions {
+    pub fn bids(&self) -> Bids<'_> {
+        Bids::new(self)
+    }
+
+    pub fn instructions(&self) -> Instructions<'_> {
         Instructions::new(self)
     }
 
-    pub fn collections(&self) -> Collections {
+    pub fn collections(&self) -> Collections<'_> {
         Collections::new(self)
     }
 
-    pub fn marketplace(&self) -> Marketplace {
+    pub fn marketplace(&self) -> Marketplace<'_> {
         Marketplace::new(self)
     }
 
-    pub fn mmm(&self) -> Mmm {
+    pub fn launchpad(&self) -> Launchpad<'_> {
+        Launchpad::new(self)
+    }
+
+    pub fn mmm(&self) -> Mmm<'_> {
         Mmm::new(self)
     }
 
-    pub fn tokens(&self) -> Tokens {
+    pub fn reprice(&self) -> Reprice<'_> {
+        Reprice::new(self)
+    }
+
+    pub fn sweep(&self) -> Sweep<'_> {
+        Sweep::new(self)
+    }
+
+    pub fn tokens(&self) -> Tokens<'_> {
         Tokens::new(self)
     }
 
-    pub fn wallets(&self) -> Wallets {
+    pub fn wallets(&self) -> Wallets<'_> {
         Wallets::new(self)
     }
 
-    pub(crate) async fn get<O>(&self, path: &str) -> Result<O, MagicedenError>
+    pub fn ordinals(&self) -> Ordinals<'_> {
+        Ordinals::new(self)
+    }
+
+    pub fn evm(&self, chain: EvmChain) -> Evm<'_> {
+        Evm::new(self, chain)
+    }
+
+    pub(crate) async fn get<P, O>(&self, path: P) -> Result<O, MagicedenError>
     where
+        P: Into<Path>,
         O: DeserializeOwned,
     {
-        let request_maker = || async {
-            Ok(self
-                .http_client
-                .get(self.url(path))
-                .query(&self.query())
-                .headers(self.headers())
-                .build()?)
-        };
+        self.get_from(&self.api_base, path).await
+    }
 
-        self.execute(request_maker).await
+    /// Make a GET request to {base}{path} and deserialize the response body
+    pub(crate) async fn get_from<P, O>(&self, base: &str, path: P) -> Result<O, MagicedenError>
+    where
+        P: Into<Path>,
+        O: DeserializeOwned,
+    {
+        self.get_with_options_from(base, path, &(), &RequestOptions::default())
+            .await
     }
 
-    pub(crate) async fn get_with_query<Q, O>(
+    pub(crate) async fn get_with_query<P, Q, O>(
         &self,
-        path: &str,
+        path: P,
+        query: &Q,
+    ) -> Result<O, MagicedenError>
+    where
+        P: Into<Path>,
+        Q: Serialize + ?Sized,
+        O: DeserializeOwned,
+    {
+        self.get_with_query_from(&self.api_base, path, query).await
+    }
+
+    /// Make a GET request with query params to {base}{path} and deserialize the response body
+    pub(crate) async fn get_with_query_from<P, Q, O>(
+        &self,
+        base: &str,
+        path: P,
+        query: &Q,
+    ) -> Result<O, MagicedenError>
+    where
+        P: Into<Path>,
+        Q: Serialize + ?Sized,
+        O: DeserializeOwned,
+    {
+        self.get_with_options_from(base, path, query, &RequestOptions::default())
+            .await
+    }
+
+    pub(crate) async fn get_with_options<P, Q, O>(
+        &self,
+        path: P,
+        query: &Q,
+        options: &RequestOptions,
+    ) -> Result<O, MagicedenError>
+    where
+        P: Into<Path>,
+        Q: Serialize + ?Sized,
+        O: DeserializeOwned,
+    {
+        self.get_with_options_from(&self.api_base, path, query, options)
+            .await
+    }
+
+    /// Make a GET request with query params and `options` to {base}{path} and deserialize the response body
+    pub(crate) async fn get_with_options_from<P, Q, O>(
+        &self,
+        base: &str,
+        path: P,
         query: &Q,
+        options: &RequestOptions,
     ) -> Result<O, MagicedenError>
     where
+        P: Into<Path>,
         Q: Serialize + ?Sized,
         O: DeserializeOwned,
     {
+        let path = path.into();
         let request_maker = || async {
-            Ok(self
+            let request = self
                 .http_client
-                .get(self.url(path))
+                .get(self.url(base, &path.path))
                 .query(&self.query())
                 .query(&query)
-                .headers(self.headers())
-                .build()?)
+                .headers(self.headers());
+            Ok(options.apply(request).build()?)
         };
 
-        self.execute(request_maker).await
+        self.execute(path.endpoint, options, request_maker).await
     }
 
     /// Make a POST request to {path} and deserialize the response body
-    pub(crate) async fn post<I, O>(&self, path: &str, request: I) -> Result<O, MagicedenError>
+    pub(crate) async fn post<P, I, O>(&self, path: P, request: I) -> Result<O, MagicedenError>
     where
+        P: Into<Path>,
         I: Serialize,
         O: DeserializeOwned,
     {
+        self.post_with_options(path, request, &RequestOptions::default())
+            .await
+    }
+
+    /// Make a POST request with `options` to {path} and deserialize the response body
+    pub(crate) async fn post_with_options<P, I, O>(
+        &self,
+        path: P,
+        request: I,
+        options: &RequestOptions,
+    ) -> Result<O, MagicedenError>
+    where
+        P: Into<Path>,
+        I: Serialize,
+        O: DeserializeOwned,
+    {
+        let path = path.into();
         let request_maker = || async {
-            Ok(self
+            let builder = self
                 .http_client
-                .post(self.url(path))
+                .post(self.url(&self.api_base, &path.path))
                 .query(&self.query())
                 .headers(self.headers())
-                .json(&request)
-                .build()?)
+                .json(&request);
+            Ok(options.apply(builder).build()?)
         };
 
-        self.execute(request_maker).await
+        self.execute(path.endpoint, options, request_maker).await
+    }
+
+    /// Subscribe to the Server-Sent Events served at {path} and deserialize the data of every message.
+    ///
+    /// The connection is re-established with the client's backoff when it drops, sending the
+    /// `Last-Event-ID` of the last received message so the server can resume the stream.
+    pub async fn subscribe<Q, O>(
+        &self,
+        path: &str,
+        query: &Q,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<O, MagicedenError>> + Send>>, MagicedenError>
+    where
+        Q: Serialize + ?Sized,
+        O: DeserializeOwned + Send + 'static,
+    {
+        let mut request = self
+            .http_client
+            .get(self.url(&self.api_base, path))
+            .query(&self.query())
+            .query(query)
+            .headers(self.headers());
+        let key = self.api_keys.select();
+        if let Some(key) = key {
+            self.api_keys.acquire(key).await;
+        }
+        if let Some((name, value)) = self.authorization(key) {
+            request = request.header(name, value);
+        }
+
+        let mut event_source = EventSource::new(request)
+            .map_err(|e| MagicedenError::InvalidArgument(e.to_string()))?;
+        event_source.set_retry_policy(Box::new(
+            reqwest_eventsource::retry::ExponentialBackoff::new(
+                self.backoff.initial_interval,
+                self.backoff.multiplier,
+                Some(self.backoff.max_interval),
+                None,
+            ),
+        ));
+
+        Ok(Self::execute_stream(event_source))
     }
 
-    async fn execute_raw<M, Fut>(&self, request_maker: M) -> Result<Bytes, MagicedenError>
+    /// Forward the events of `event_source` through a channel of `STREAM_BUFFER` events, the event source is
+    /// not polled while the consumer lags behind and is closed once the consumer drops the stream
+    fn execute_stream<O>(
+        mut event_source: EventSource,
+    ) -> Pin<Box<dyn Stream<Item = Result<O, MagicedenError>> + Send>>
+    where
+        O: DeserializeOwned + Send + 'static,
+    {
+        let (tx, rx) = tokio::sync::mpsc::channel(STREAM_BUFFER);
+
+        tokio::spawn(async move {
+            loop {
+                // Stop on a dropped receiver even while the stream is idle
+                let event = tokio::select! {
+                    event = event_source.next() => event,
+                    _ = tx.closed() => break,
+                };
+                let Some(event) = event else {
+                    break;
+                };
+                let response = match event {
+                    // The event source reconnects by itself when the server closes the stream
+                    Err(reqwest_eventsource::Error::StreamEnded) => continue,
+                    Err(e) => {
+                        let closed = matches!(
+                            e,
+                            reqwest_eventsource::Error::InvalidStatusCode(_)
+                                | reqwest_eventsource::Error::InvalidContentType(_)
+                        );
+                        if tx
+                            .send(Err(MagicedenError::StreamError(e.to_string())))
+                            .await
+                            .is_err()
+                            || closed
+                        {
+                            break;
+                        }
+                        continue;
+                    }
+                    Ok(Event::Open) => continue,
+                    Ok(Event::Message(message)) => serde_json::from_str::<O>(&message.data)
+                        .map_err(|e| map_deserialization_error(e, message.data.as_bytes())),
+                };
+
+                if tx.send(response).await.is_err() {
+                    break;
+                }
+            }
+
+            event_source.close();
+        });
+
+        Box::pin(tokio_stream::wrappers::ReceiverStream::new(rx))
+    }
+
+    /// Execute the request built by `request_maker`, retrying rate limited requests with the client's backoff
+    /// up to the `max_retries` of `options`.
+    ///
+    /// Every attempt is authorized with the next key of the pool, after waiting for the key's rate limit.
+    /// When the quota of a key is exceeded the request is sent again right away with another key.
+    ///
+    /// Runs in a `request` span recording the method, endpoint, path with its parameters, number of attempts,
+    /// last status and latency of the last attempt; every retry is logged with its delay. Headers and query
+    /// parameters are never recorded so the API key does not end up in logs.
+    async fn execute_raw<M, Fut>(
+        &self,
+        endpoint: &'static str,
+        options: &RequestOptions,
+        request_maker: M,
+    ) -> Result<Bytes, MagicedenError>
     where
         M: Fn() -> Fut,
         Fut: core::future::Future<Output = Result<reqwest::Request, MagicedenError>>,
     {
-        let client = self.http_client.clone();
-
-        backoff::future::retry(self.backoff.clone(), || async {
-            let request = request_maker().await.map_err(backoff::Error::Permanent)?;
-            let response = client
-                .execute(request)
-                .await
-                .map_err(MagicedenError::Reqwest)
-                .map_err(backoff::Error::Permanent)?;
-            let status = response.status();
-            let by = response.bytes().await;
-            // println!("response: {:?}", by);
-            let bytes = by
-                .map_err(MagicedenError::Reqwest)
-                .map_err(backoff::Error::Permanent)?;
-
-            // Deserialize response body from either error object or actual response object
-            if !status.is_success() {
-                let wrapped_error: WrappedError = serde_json::from_slice(bytes.as_ref())
-                    .map_err(|e| map_deserialization_error(e, bytes.as_ref()))
+        let client = &self.http_client;
+        let keys = &*self.api_keys;
+        let request_maker = &request_maker;
+        let span = tracing::info_span!(
+            "request",
+            method = tracing::field::Empty,
+            endpoint = %endpoint,
+            path = tracing::field::Empty,
+            key = tracing::field::Empty,
+            attempt = tracing::field::Empty,
+            status = tracing::field::Empty,
+            latency_ms = tracing::field::Empty,
+        );
+        let mut attempt = 0u32;
+        let failovers = AtomicU32::new(0);
+        let failovers = &failovers;
+
+        let operation = || {
+            attempt += 1;
+            let attempt = attempt;
+            let span = span.clone();
+            async move {
+                let key = keys.select();
+                if let Some(key) = key {
+                    keys.acquire(key).await;
+                    span.record("key", key);
+                }
+                let mut request = request_maker().await.map_err(backoff::Error::Permanent)?;
+                if let Some((name, value)) = self.authorization(key) {
+                    request.headers_mut().entry(name).or_insert(value);
+                }
+                let method = request.method().clone();
+                if attempt == 1 {
+                    span.record("method", tracing::field::display(&method));
+                    span.record("path", tracing::field::display(request.url().path()));
+                }
+                span.record("attempt", attempt);
+
+                let start = std::time::Instant::now();
+                let response = client.execute(request).await.map_err(|e| {
+                    metrics::response(endpoint, &method, None, start.elapsed());
+                    backoff::Error::Permanent(MagicedenError::Reqwest(e))
+                })?;
+                let status = response.status();
+                let by = response.bytes().await;
+                let latency = start.elapsed();
+                let latency_ms = latency.as_millis() as u64;
+                span.record("status", status.as_u16());
+                span.record("latency_ms", latency_ms);
+                tracing::debug!(attempt, status = status.as_u16(), latency_ms, "response");
+                metrics::response(endpoint, &method, Some(status), latency);
+                let bytes = by
+                    .map_err(MagicedenError::Reqwest)
                     .map_err(backoff::Error::Permanent)?;
 
-                if status.as_u16() == 429
+                // Deserialize response body from either error object or actual response object
+                if !status.is_success() {
+                    let wrapped_error: WrappedError = serde_json::from_slice(bytes.as_ref())
+                        .map_err(|e| {
+                            metrics::deserialization_failure(endpoint);
+                            map_deserialization_error(e, bytes.as_ref())
+                        })
+                        .map_err(backoff::Error::Permanent)?;
+
+                    let insufficient_quota =
+                        wrapped_error.error.r#type == Some("insufficient_quota".to_string());
+                    if status.as_u16() == 429 {
+                        metrics::rate_limited(endpoint);
+                        if let Some(key) = key.filter(|_| !insufficient_quota) {
+                            keys.rate_limited(key);
+                        }
+                    }
+                    if status.as_u16() == 429
+                        && insufficient_quota
+                        && key.is_some_and(|key| keys.exhausted(key))
+                    {
+                        // Fail over to another key of the pool
+                        tracing::warn!("Quota exceeded: {}", wrapped_error.error.message);
+                        failovers.fetch_add(1, Ordering::Relaxed);
+                        return Err(backoff::Error::Transient {
+                            err: MagicedenError::ApiError(wrapped_error.error),
+                            retry_after: Some(Duration::ZERO),
+                        });
+                    }
+                    if status.as_u16() == 429
                         // API returns 429 also when:
                         // "You exceeded your current quota, please check your plan and billing details."
-                        && wrapped_error.error.r#type != Some("insufficient_quota".to_string())
-                {
-                    // Rate limited retry...
-                    tracing::warn!("Rate limited: {}", wrapped_error.error.message);
-                    return Err(backoff::Error::Transient {
-                        err: MagicedenError::ApiError(wrapped_error.error),
-                        retry_after: None,
-                    });
-                } else {
-                    return Err(backoff::Error::Permanent(MagicedenError::ApiError(
-                        wrapped_error.error,
-                    )));
+                        && !insufficient_quota
+                        && options.max_retries.is_none_or(|max| {
+                            attempt - failovers.load(Ordering::Relaxed) <= max
+                        })
+                    {
+                        // Rate limited retry...
+                        tracing::warn!("Rate limited: {}", wrapped_error.error.message);
+                        return Err(backoff::Error::Transient {
+                            err: MagicedenError::ApiError(wrapped_error.error),
+                            retry_after: None,
+                        });
+                    } else {
+                        return Err(backoff::Error::Permanent(MagicedenError::ApiError(
+                            wrapped_error.error,
+                        )));
+                    }
                 }
+
+                if let Some(key) = key {
+                    keys.succeeded(key);
+                }
+
+                Ok(bytes)
             }
+        };
 
-            Ok(bytes)
-        })
-        .await
+        let notify = |e: MagicedenError, delay: std::time::Duration| {
+            metrics::retry(endpoint);
+            tracing::info!(
+                retry_delay_ms = delay.as_millis() as u64,
+                error = %e,
+                "retrying request"
+            );
+        };
+
+        backoff::future::retry_notify(self.backoff.clone(), operation, notify)
+            .instrument(span.clone())
+            .await
     }
 
-    async fn execute<O, M, Fut>(&self, request_maker: M) -> Result<O, MagicedenError>
+    async fn execute<O, M, Fut>(
+        &self,
+        endpoint: &'static str,
+        options: &RequestOptions,
+        request_maker: M,
+    ) -> Result<O, MagicedenError>
     where
         M: Fn() -> Fut,
         O: DeserializeOwned,
         Fut: core::future::Future<Output = Result<reqwest::Request, MagicedenError>>,
     {
-        let bytes = self.execute_raw(request_maker).await?;
+        let bytes = self.execute_raw(endpoint, options, request_maker).await?;
 
-        let response: O = serde_json::from_slice(bytes.as_ref())
-            .map_err(|e| map_deserialization_error(e, bytes.as_ref()))?;
+        let response: O = serde_json::from_slice(bytes.as_ref()).map_err(|e| {
+            metrics::deserialization_failure(endpoint);
+            map_deserialization_error(e, bytes.as_ref())
+        })?;
 
         Ok(response)
     }
@@ -0,0 +1,89 @@
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+use crate::{client::API_BASE, error::MagicedenError};
+
+/// Devnet v2 API base url
+pub const DEVNET_API_BASE: &str = "https://api-devnet.magiceden.dev/v2";
+
+/// Mainnet auction house of the Magic Eden v2 program, used when a mainnet request leaves its address empty
+pub const AUCTION_HOUSE_ADDRESS: &str = "E8cU1WiRWjanGxmn96ewBgk9vPTcL6AEZ1t6F6fkgUWe";
+
+/// MMM program, deployed at the same address on mainnet and devnet
+pub const MMM_PROGRAM_ID: &str = "mmm3XBJg5gk8XJxEKBvdgptZz6SgK4tXvn36sodowMc";
+
+/// Solana cluster the v2 API serves, selecting the API base, the default auction house and the MMM program.
+///
+/// Parsed from `mainnet`, `devnet` or the url of a custom API base, e.g. a relay mirroring mainnet.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "String")]
+pub enum Cluster {
+    #[default]
+    Mainnet,
+    Devnet,
+    /// API served at a custom base url
+    Custom(String),
+}
+
+impl Cluster {
+    /// The cluster served at `api_base`
+    pub fn from_api_base(api_base: &str) -> Self {
+        match api_base {
+            API_BASE => Cluster::Mainnet,
+            DEVNET_API_BASE => Cluster::Devnet,
+            _ => Cluster::Custom(api_base.to_string()),
+        }
+    }
+
+    pub fn api_base(&self) -> &str {
+        match self {
+            Cluster::Mainnet => API_BASE,
+            Cluster::Devnet => DEVNET_API_BASE,
+            Cluster::Custom(api_base) => api_base,
+        }
+    }
+
+    /// Auction house filled in instruction requests without one, only known for mainnet.
+    /// Other clusters take it from [`Client::with_auction_house_address`](crate::Client::with_auction_house_address)
+    /// so a request never falls back to a mainnet account.
+    pub fn auction_house_address(&self) -> Option<&'static str> {
+        match self {
+            Cluster::Mainnet => Some(AUCTION_HOUSE_ADDRESS),
+            Cluster::Devnet | Cluster::Custom(_) => None,
+        }
+    }
+
+    /// MMM program that pools of the cluster belong to, unknown for a custom API base
+    pub fn mmm_program_id(&self) -> Option<&'static str> {
+        match self {
+            Cluster::Mainnet | Cluster::Devnet => Some(MMM_PROGRAM_ID),
+            Cluster::Custom(_) => None,
+        }
+    }
+}
+
+impl FromStr for Cluster {
+    type Err = MagicedenError;
+
+    fn from_str(cluster: &str) -> Result<Self, Self::Err> {
+        match cluster {
+            "mainnet" | "mainnet-beta" => Ok(Cluster::Mainnet),
+            "devnet" => Ok(Cluster::Devnet),
+            url if url.starts_with("http://") || url.starts_with("https://") => {
+                Ok(Cluster::Custom(url.to_string()))
+            }
+            _ => Err(MagicedenError::InvalidArgument(format!(
+                "unknown cluster `{cluster}`, expected mainnet, devnet or an API base url"
+            ))),
+        }
+    }
+}
+
+impl TryFrom<String> for Cluster {
+    type Error = MagicedenError;
+
+    fn try_from(cluster: String) -> Result<Self, Self::Error> {
+        cluster.parse()
+    }
+}
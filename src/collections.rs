@@ -1,11 +1,17 @@
+use std::{pin::Pin, time::Duration};
+
+use futures::Stream;
+
 use crate::{
+    client::endpoint,
     error::MagicedenError,
     types::{
-        CollectionActivitiesRequest, CollectionActivitiesResponse, CollectionListingsRequest,
-        CollectionListingsResponse, CollectionStatsResponse, CollectionsRequest,
-        CollectionsResponse, HolderStatsResponse,
+        CollectionActivitiesRequest, CollectionActivitiesResponse, CollectionActivitiy,
+        CollectionAttributesResponse, CollectionListingsRequest, CollectionListingsResponse,
+        CollectionStatsResponse, CollectionTimeSeriesRequest, CollectionTimeSeriesResponse,
+        CollectionsRequest, CollectionsResponse, HolderStatsResponse, ListingEvent, RequestOptions,
     },
-    Client,
+    watch, Client,
 };
 
 pub struct Collections<'c> {
@@ -23,17 +29,44 @@ impl<'c> Collections<'c> {
         &self,
         symbol: &str,
         request: CollectionActivitiesRequest,
+    ) -> Result<CollectionActivitiesResponse, MagicedenError> {
+        self.activities_with_options(symbol, request, &RequestOptions::default())
+            .await
+    }
+
+    /// Same as [`Self::activities`] with per-request [`RequestOptions`]
+    #[tracing::instrument(name = "activities", skip(self, request, options))]
+    pub async fn activities_with_options(
+        &self,
+        symbol: &str,
+        request: CollectionActivitiesRequest,
+        options: &RequestOptions,
     ) -> Result<CollectionActivitiesResponse, MagicedenError> {
         self.client
-            .get_with_query(&format!("/collections/{symbol}/activities"), &request)
+            .get_with_options(
+                endpoint!("/collections/{symbol}/activities"),
+                &request,
+                options,
+            )
             .await
     }
 
     /// Get stats of a collection
     /// @path: /collections/{symbol}/stats
     pub async fn stats(&self, symbol: &str) -> Result<CollectionStatsResponse, MagicedenError> {
+        self.stats_with_options(symbol, &RequestOptions::default())
+            .await
+    }
+
+    /// Same as [`Self::stats`] with per-request [`RequestOptions`]
+    #[tracing::instrument(name = "stats", skip(self, options))]
+    pub async fn stats_with_options(
+        &self,
+        symbol: &str,
+        options: &RequestOptions,
+    ) -> Result<CollectionStatsResponse, MagicedenError> {
         self.client
-            .get(&format!("/collections/{symbol}/stats"))
+            .get_with_options(endpoint!("/collections/{symbol}/stats"), &(), options)
             .await
     }
 
@@ -43,7 +76,20 @@ impl<'c> Collections<'c> {
         &self,
         request: CollectionsRequest,
     ) -> Result<CollectionsResponse, MagicedenError> {
-        self.client.get_with_query("/collections", &request).await
+        self.collections_with_options(request, &RequestOptions::default())
+            .await
+    }
+
+    /// Same as [`Self::collections`] with per-request [`RequestOptions`]
+    #[tracing::instrument(name = "collections", skip(self, request, options))]
+    pub async fn collections_with_options(
+        &self,
+        request: CollectionsRequest,
+        options: &RequestOptions,
+    ) -> Result<CollectionsResponse, MagicedenError> {
+        self.client
+            .get_with_options("/collections", &request, options)
+            .await
     }
 
     /// Get listings of a collection
@@ -52,17 +98,154 @@ impl<'c> Collections<'c> {
         &self,
         symbol: &str,
         request: CollectionListingsRequest,
+    ) -> Result<CollectionListingsResponse, MagicedenError> {
+        self.listings_with_options(symbol, request, &RequestOptions::default())
+            .await
+    }
+
+    /// Same as [`Self::listings`] with per-request [`RequestOptions`]
+    #[tracing::instrument(name = "listings", skip(self, request, options))]
+    pub async fn listings_with_options(
+        &self,
+        symbol: &str,
+        request: CollectionListingsRequest,
+        options: &RequestOptions,
     ) -> Result<CollectionListingsResponse, MagicedenError> {
         self.client
-            .get_with_query(&format!("/collections/{symbol}/listings"), &request)
+            .get_with_options(
+                endpoint!("/collections/{symbol}/listings"),
+                &request,
+                options,
+            )
             .await
     }
 
     /// Get holder stats of a collection
     /// @path: /collections/{symbol}/holder_stats
     pub async fn holder_stats(&self, symbol: &str) -> Result<HolderStatsResponse, MagicedenError> {
+        self.holder_stats_with_options(symbol, &RequestOptions::default())
+            .await
+    }
+
+    /// Same as [`Self::holder_stats`] with per-request [`RequestOptions`]
+    #[tracing::instrument(name = "holder_stats", skip(self, options))]
+    pub async fn holder_stats_with_options(
+        &self,
+        symbol: &str,
+        options: &RequestOptions,
+    ) -> Result<HolderStatsResponse, MagicedenError> {
+        self.client
+            .get_with_options(
+                endpoint!("/collections/{symbol}/holder_stats"),
+                &(),
+                options,
+            )
+            .await
+    }
+
+    /// Get attributes of a collection with listed counts and per-trait floor prices
+    /// @path: /collections/{symbol}/attributes
+    pub async fn attributes(
+        &self,
+        symbol: &str,
+    ) -> Result<CollectionAttributesResponse, MagicedenError> {
+        self.attributes_with_options(symbol, &RequestOptions::default())
+            .await
+    }
+
+    /// Same as [`Self::attributes`] with per-request [`RequestOptions`]
+    #[tracing::instrument(name = "attributes", skip(self, options))]
+    pub async fn attributes_with_options(
+        &self,
+        symbol: &str,
+        options: &RequestOptions,
+    ) -> Result<CollectionAttributesResponse, MagicedenError> {
+        self.client
+            .get_with_options(endpoint!("/collections/{symbol}/attributes"), &(), options)
+            .await
+    }
+
+    /// Get historical stats of a collection
+    /// @path: /collections/{symbol}/time_series
+    pub async fn time_series(
+        &self,
+        symbol: &str,
+        request: CollectionTimeSeriesRequest,
+    ) -> Result<CollectionTimeSeriesResponse, MagicedenError> {
+        self.time_series_with_options(symbol, request, &RequestOptions::default())
+            .await
+    }
+
+    /// Same as [`Self::time_series`] with per-request [`RequestOptions`]
+    #[tracing::instrument(name = "time_series", skip(self, request, options))]
+    pub async fn time_series_with_options(
+        &self,
+        symbol: &str,
+        request: CollectionTimeSeriesRequest,
+        options: &RequestOptions,
+    ) -> Result<CollectionTimeSeriesResponse, MagicedenError> {
+        self.client
+            .get_with_options(
+                endpoint!("/collections/{symbol}/time_series"),
+                &request,
+                options,
+            )
+            .await
+    }
+
+    /// Follow the activities of a collection by polling every `interval`.
+    ///
+    /// Yields activities that happened after the stream started, oldest first and de-duplicated by signature.
+    /// Gaps between two polls are filled by paging back until a known signature is met.
+    /// Errors are yielded and polling resumes after the client's backoff delay.
+    pub fn watch_activities(
+        &self,
+        symbol: &str,
+        interval: Duration,
+    ) -> impl Stream<Item = Result<CollectionActivitiy, MagicedenError>> + 'c {
+        watch::activities(self.client, symbol, interval)
+    }
+
+    /// Follow the listings of a collection by polling every `interval`.
+    ///
+    /// Yields the changes between two polls of the listings selected by `request`,
+    /// listings moving out of the requested page are reported as delisted.
+    pub fn watch_listings(
+        &self,
+        symbol: &str,
+        request: CollectionListingsRequest,
+        interval: Duration,
+    ) -> impl Stream<Item = Result<ListingEvent, MagicedenError>> + 'c {
+        watch::listings(self.client, symbol, request, interval)
+    }
+
+    /// Subscribe to the activities of a collection streamed as Server-Sent Events.
+    /// Requires an api base serving the stream, e.g. a relay mirroring the API.
+    /// @path: /collections/{symbol}/activities/stream
+    pub async fn subscribe_activities(
+        &self,
+        symbol: &str,
+    ) -> Result<
+        Pin<Box<dyn Stream<Item = Result<CollectionActivitiy, MagicedenError>> + Send>>,
+        MagicedenError,
+    > {
+        self.client
+            .subscribe(&format!("/collections/{symbol}/activities/stream"), &())
+            .await
+    }
+
+    /// Subscribe to the listing changes of a collection streamed as Server-Sent Events.
+    /// Requires an api base serving the stream, e.g. a relay mirroring the API.
+    /// @path: /collections/{symbol}/listings/stream
+    pub async fn subscribe_listings(
+        &self,
+        symbol: &str,
+    ) -> Result<
+        Pin<Box<dyn Stream<Item = Result<ListingEvent, MagicedenError>> + Send>>,
+        MagicedenError,
+    > {
         self.client
-            .get(&format!("/collections/{symbol}/holder_stats"))
+            .subscribe(&format!("/collections/{symbol}/listings/stream"), &())
             .await
     }
 }
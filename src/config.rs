@@ -0,0 +1,314 @@
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::Duration,
+};
+
+use serde::Deserialize;
+use zeroize::Zeroizing;
+
+use crate::{
+    client::{API_KEY_ENV, API_KEY_FILE_ENV},
+    error::MagicedenError,
+    ApiKey, ApiKeys, Client, Cluster, KeySelection,
+};
+
+/// Configuration of a [`Client`], deserializable from JSON or, with the `toml` feature, TOML and
+/// overridable by `MAGICEDEN_*` environment variables. Unset settings keep the client defaults.
+///
+/// ```toml
+/// api_key = "..."
+/// cluster = "devnet"
+/// auction_house_address = "..."
+/// timeout_ms = 10000
+/// proxy = "http://localhost:8080"
+///
+/// [backoff]
+/// initial_interval_ms = 500
+/// max_elapsed_time_ms = 60000
+///
+/// [rate_limit]
+/// requests = 2
+/// period_ms = 1000
+/// ```
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default)]
+pub struct ClientConfig {
+    /// `MAGICEDEN_API_KEY`
+    pub api_key: Option<ApiKey>,
+    /// File holding the API key, read when `api_key` is not set, `MAGICEDEN_API_KEY_FILE`
+    pub api_key_file: Option<PathBuf>,
+    /// More keys for a key pool, comma separated in `MAGICEDEN_API_KEYS`
+    pub api_keys: Vec<ApiKey>,
+    /// `round_robin` or `least_used`, `MAGICEDEN_KEY_SELECTION`
+    pub key_selection: Option<KeySelection>,
+    /// `mainnet`, `devnet` or a custom API base url, `MAGICEDEN_CLUSTER`.
+    /// Building fails when `api_base` is set to the base of another cluster.
+    pub cluster: Option<Cluster>,
+    /// `MAGICEDEN_API_BASE`
+    pub api_base: Option<String>,
+    /// Auction house filled in instruction requests without one, required by clusters other than
+    /// mainnet, `MAGICEDEN_AUCTION_HOUSE_ADDRESS`
+    pub auction_house_address: Option<String>,
+    /// `MAGICEDEN_ORDINALS_API_BASE`
+    pub ordinals_api_base: Option<String>,
+    /// `MAGICEDEN_EVM_API_BASE`
+    pub evm_api_base: Option<String>,
+    /// Timeout of a request attempt, `MAGICEDEN_TIMEOUT_MS`
+    pub timeout_ms: Option<u64>,
+    /// `MAGICEDEN_CONNECT_TIMEOUT_MS`
+    pub connect_timeout_ms: Option<u64>,
+    /// Proxy url for every request, `MAGICEDEN_PROXY`
+    pub proxy: Option<String>,
+    pub backoff: BackoffConfig,
+    /// Rate limit of every API key
+    pub rate_limit: Option<RateLimitConfig>,
+}
+
+/// Retry backoff settings, unset ones keep the defaults of [`backoff::ExponentialBackoff`]
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct BackoffConfig {
+    /// `MAGICEDEN_BACKOFF_INITIAL_INTERVAL_MS`
+    pub initial_interval_ms: Option<u64>,
+    /// `MAGICEDEN_BACKOFF_MAX_INTERVAL_MS`
+    pub max_interval_ms: Option<u64>,
+    /// Time after which retries stop, `MAGICEDEN_BACKOFF_MAX_ELAPSED_TIME_MS`
+    pub max_elapsed_time_ms: Option<u64>,
+    /// `MAGICEDEN_BACKOFF_MULTIPLIER`
+    pub multiplier: Option<f64>,
+    /// `MAGICEDEN_BACKOFF_RANDOMIZATION_FACTOR`
+    pub randomization_factor: Option<f64>,
+}
+
+/// Requests allowed per period, see [`ApiKeys::with_rate_limit`]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct RateLimitConfig {
+    /// `MAGICEDEN_RATE_LIMIT_REQUESTS`
+    pub requests: u32,
+    /// `MAGICEDEN_RATE_LIMIT_PERIOD_MS`, one second by default
+    #[serde(default = "default_period_ms")]
+    pub period_ms: u64,
+}
+
+fn default_period_ms() -> u64 {
+    1000
+}
+
+impl ClientConfig {
+    #[cfg(feature = "toml")]
+    pub fn from_toml(toml: &str) -> Result<Self, MagicedenError> {
+        toml::from_str(toml).map_err(|e| MagicedenError::Config(e.to_string()))
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, MagicedenError> {
+        serde_json::from_str(json).map_err(|e| MagicedenError::Config(e.to_string()))
+    }
+
+    /// Read a `.toml` file, which requires the `toml` feature, or a JSON file for any other extension
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, MagicedenError> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path).map_err(|e| {
+            MagicedenError::Config(format!("failed to read {}: {e}", path.display()))
+        })?;
+        let config = match path.extension() {
+            #[cfg(feature = "toml")]
+            Some(extension) if extension == "toml" => Self::from_toml(&contents),
+            #[cfg(not(feature = "toml"))]
+            Some(extension) if extension == "toml" => Err(MagicedenError::Config(
+                "TOML configuration requires the `toml` feature".to_string(),
+            )),
+            _ => Self::from_json(&contents),
+        };
+        config.map_err(|e| MagicedenError::Config(format!("{}: {e}", path.display())))
+    }
+
+    /// Configuration from the `MAGICEDEN_*` environment variables alone
+    pub fn from_env() -> Result<Self, MagicedenError> {
+        Self::default().with_env()
+    }
+
+    /// Override the settings with the `MAGICEDEN_*` environment variables that are set
+    pub fn with_env(mut self) -> Result<Self, MagicedenError> {
+        if let Some(api_key) = env::<String>(API_KEY_ENV)? {
+            self.api_key = Some(ApiKey::new(api_key));
+        }
+        set(&mut self.api_key_file, API_KEY_FILE_ENV)?;
+        if let Some(api_keys) = env::<String>("MAGICEDEN_API_KEYS")? {
+            let api_keys = Zeroizing::new(api_keys);
+            self.api_keys = api_keys
+                .split(',')
+                .map(str::trim)
+                .filter(|key| !key.is_empty())
+                .map(ApiKey::from)
+                .collect();
+        }
+        if let Some(selection) = env::<String>("MAGICEDEN_KEY_SELECTION")? {
+            self.key_selection = Some(
+                serde_json::from_value(serde_json::Value::String(selection))
+                    .map_err(|e| MagicedenError::Config(format!("MAGICEDEN_KEY_SELECTION: {e}")))?,
+            );
+        }
+        set(&mut self.cluster, "MAGICEDEN_CLUSTER")?;
+        set(&mut self.api_base, "MAGICEDEN_API_BASE")?;
+        set(
+            &mut self.auction_house_address,
+            "MAGICEDEN_AUCTION_HOUSE_ADDRESS",
+        )?;
+        set(&mut self.ordinals_api_base, "MAGICEDEN_ORDINALS_API_BASE")?;
+        set(&mut self.evm_api_base, "MAGICEDEN_EVM_API_BASE")?;
+        set(&mut self.timeout_ms, "MAGICEDEN_TIMEOUT_MS")?;
+        set(&mut self.connect_timeout_ms, "MAGICEDEN_CONNECT_TIMEOUT_MS")?;
+        set(&mut self.proxy, "MAGICEDEN_PROXY")?;
+
+        let backoff = &mut self.backoff;
+        set(
+            &mut backoff.initial_interval_ms,
+            "MAGICEDEN_BACKOFF_INITIAL_INTERVAL_MS",
+        )?;
+        set(
+            &mut backoff.max_interval_ms,
+            "MAGICEDEN_BACKOFF_MAX_INTERVAL_MS",
+        )?;
+        set(
+            &mut backoff.max_elapsed_time_ms,
+            "MAGICEDEN_BACKOFF_MAX_ELAPSED_TIME_MS",
+        )?;
+        set(&mut backoff.multiplier, "MAGICEDEN_BACKOFF_MULTIPLIER")?;
+        set(
+            &mut backoff.randomization_factor,
+            "MAGICEDEN_BACKOFF_RANDOMIZATION_FACTOR",
+        )?;
+
+        if let Some(requests) = env("MAGICEDEN_RATE_LIMIT_REQUESTS")? {
+            let period_ms = self.rate_limit.as_ref().map(|limit| limit.period_ms);
+            self.rate_limit = Some(RateLimitConfig {
+                requests,
+                period_ms: period_ms.unwrap_or_else(default_period_ms),
+            });
+        }
+        if let (Some(limit), Some(period_ms)) =
+            (&mut self.rate_limit, env("MAGICEDEN_RATE_LIMIT_PERIOD_MS")?)
+        {
+            limit.period_ms = period_ms;
+        }
+
+        Ok(self)
+    }
+
+    /// Create the configured client
+    pub fn build(self) -> Result<Client, MagicedenError> {
+        let mut http_client = reqwest::Client::builder();
+        if let Some(timeout_ms) = self.timeout_ms {
+            http_client = http_client.timeout(Duration::from_millis(timeout_ms));
+        }
+        if let Some(connect_timeout_ms) = self.connect_timeout_ms {
+            http_client = http_client.connect_timeout(Duration::from_millis(connect_timeout_ms));
+        }
+        if let Some(proxy) = &self.proxy {
+            http_client = http_client.proxy(reqwest::Proxy::all(proxy)?);
+        }
+        let mut client = Client::new().with_http_client(http_client.build()?);
+
+        match (self.cluster, self.api_base) {
+            (Some(cluster), Some(api_base)) if cluster.api_base() != api_base => {
+                return Err(MagicedenError::Config(format!(
+                    "api_base {api_base} is not the API base {} of the {cluster:?} cluster",
+                    cluster.api_base()
+                )));
+            }
+            (Some(cluster), _) => client = client.with_cluster(cluster),
+            (None, Some(api_base)) => client = client.with_api_base(api_base),
+            (None, None) => {}
+        }
+        if let Some(auction_house_address) = self.auction_house_address {
+            client = client.with_auction_house_address(auction_house_address);
+        }
+        if let Some(ordinals_api_base) = self.ordinals_api_base {
+            client = client.with_ordinals_api_base(ordinals_api_base);
+        }
+        if let Some(evm_api_base) = self.evm_api_base {
+            client = client.with_evm_api_base(evm_api_base);
+        }
+        let backoff = self.backoff.apply(client.backoff().clone());
+        client = client.with_backoff(backoff);
+
+        let api_key = match (self.api_key, &self.api_key_file) {
+            (Some(api_key), _) => Some(api_key),
+            (None, Some(path)) => Some(read_api_key(path)?),
+            (None, None) => None,
+        };
+        let api_keys = api_key
+            .into_iter()
+            .chain(self.api_keys)
+            .filter(|key| !key.expose_secret().is_empty());
+        let mut api_keys =
+            ApiKeys::new(api_keys)?.with_selection(self.key_selection.unwrap_or_default());
+        if let Some(limit) = self.rate_limit {
+            api_keys =
+                api_keys.with_rate_limit(limit.requests, Duration::from_millis(limit.period_ms));
+        }
+
+        Ok(client.with_api_keys(api_keys))
+    }
+}
+
+impl BackoffConfig {
+    fn apply(&self, mut backoff: backoff::ExponentialBackoff) -> backoff::ExponentialBackoff {
+        if let Some(initial_interval_ms) = self.initial_interval_ms {
+            backoff.initial_interval = Duration::from_millis(initial_interval_ms);
+            backoff.current_interval = backoff.initial_interval;
+        }
+        if let Some(max_interval_ms) = self.max_interval_ms {
+            backoff.max_interval = Duration::from_millis(max_interval_ms);
+        }
+        if let Some(max_elapsed_time_ms) = self.max_elapsed_time_ms {
+            backoff.max_elapsed_time = Some(Duration::from_millis(max_elapsed_time_ms));
+        }
+        if let Some(multiplier) = self.multiplier {
+            backoff.multiplier = multiplier;
+        }
+        if let Some(randomization_factor) = self.randomization_factor {
+            backoff.randomization_factor = randomization_factor;
+        }
+        backoff
+    }
+}
+
+/// Read an API key from the first line of a file
+fn read_api_key(path: &Path) -> Result<ApiKey, MagicedenError> {
+    let contents = Zeroizing::new(std::fs::read_to_string(path).map_err(|e| {
+        MagicedenError::Config(format!(
+            "failed to read API key file {}: {e}",
+            path.display()
+        ))
+    })?);
+    Ok(ApiKey::new(contents.trim()))
+}
+
+/// Parse the environment variable `name` when it is set
+fn env<T>(name: &str) -> Result<Option<T>, MagicedenError>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    match std::env::var(name) {
+        Ok(value) => value
+            .parse()
+            .map(Some)
+            .map_err(|e| MagicedenError::Config(format!("{name}: {e}"))),
+        Err(std::env::VarError::NotPresent) => Ok(None),
+        Err(e) => Err(MagicedenError::Config(format!("{name}: {e}"))),
+    }
+}
+
+fn set<T>(setting: &mut Option<T>, name: &str) -> Result<(), MagicedenError>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    if let Some(value) = env(name)? {
+        *setting = Some(value);
+    }
+    Ok(())
+}
@@ -13,6 +13,23 @@ pub enum MagicedenError {
 
     #[error("invalid args: {0}")]
     InvalidArgument(String),
+
+    #[error("stream failed: {0}")]
+    StreamError(String),
+
+    #[error("export failed: {0}")]
+    Export(String),
+
+    #[error("invalid configuration: {0}")]
+    Config(String),
+
+    #[cfg(feature = "blocking")]
+    #[error("failed to build the blocking client runtime: {0}")]
+    Runtime(std::io::Error),
+
+    #[cfg(feature = "store")]
+    #[error("store error: {0}")]
+    Store(#[from] rusqlite::Error),
 }
 
 #[derive(Debug, Deserialize)]
@@ -31,7 +48,7 @@ pub(crate) struct WrappedError {
 pub(crate) fn map_deserialization_error(e: serde_json::Error, bytes: &[u8]) -> MagicedenError {
     tracing::error!(
         "failed deserialization of: {}",
-        String::from_utf8_lossy(bytes.as_ref())
+        String::from_utf8_lossy(bytes)
     );
     MagicedenError::JSONDeserialize(e)
 }
@@ -0,0 +1,140 @@
+use crate::{
+    client::Path,
+    error::MagicedenError,
+    types::{
+        EvmChain, EvmCollectionsRequest, EvmCollectionsResponse, EvmOrdersRequest,
+        EvmOrdersResponse, EvmSalesRequest, EvmSalesResponse, EvmTokensRequest, EvmTokensResponse,
+        EvmUserActivityRequest, EvmUserActivityResponse,
+    },
+    Client,
+};
+
+/// [`Path`] of an endpoint under the chain segment, labelled with the chain so the metrics of
+/// each chain stay apart, e.g. `/ethereum/tokens/v6`
+macro_rules! chain_endpoint {
+    ($chain:expr, $endpoint:literal) => {
+        Path::from(match $chain {
+            EvmChain::Ethereum => concat!("/ethereum", $endpoint),
+            EvmChain::Polygon => concat!("/polygon", $endpoint),
+            EvmChain::Base => concat!("/base", $endpoint),
+            EvmChain::Arbitrum => concat!("/arbitrum", $endpoint),
+            EvmChain::Bsc => concat!("/bsc", $endpoint),
+        })
+    };
+}
+
+/// EVM v3 RTP API group, requests are made against `{Client::evm_api_base}/{chain}`.
+///
+/// List endpoints are paginated with a continuation token: pass the `continuation`
+/// of a response into the next request until it comes back as `None`.
+pub struct Evm<'c> {
+    pub client: &'c Client,
+    pub chain: EvmChain,
+}
+
+impl<'c> Evm<'c> {
+    pub fn new(client: &'c Client, chain: EvmChain) -> Self {
+        Self { client, chain }
+    }
+
+    /// Base url of the chain, e.g. `https://api-mainnet.magiceden.dev/v3/rtp/ethereum`
+    pub fn base(&self) -> String {
+        format!("{}/{}", self.client.evm_api_base(), self.chain)
+    }
+
+    /// Get collections
+    /// @path: /collections/v7
+    #[tracing::instrument(name = "collections", skip(self, request), fields(chain = %self.chain))]
+    pub async fn collections(
+        &self,
+        request: EvmCollectionsRequest,
+    ) -> Result<EvmCollectionsResponse, MagicedenError> {
+        self.client
+            .get_with_query_from(
+                self.client.evm_api_base(),
+                chain_endpoint!(self.chain, "/collections/v7"),
+                &request,
+            )
+            .await
+    }
+
+    /// Get tokens with their market data
+    /// @path: /tokens/v6
+    #[tracing::instrument(name = "tokens", skip(self, request), fields(chain = %self.chain))]
+    pub async fn tokens(
+        &self,
+        request: EvmTokensRequest,
+    ) -> Result<EvmTokensResponse, MagicedenError> {
+        self.client
+            .get_with_query_from(
+                self.client.evm_api_base(),
+                chain_endpoint!(self.chain, "/tokens/v6"),
+                &request,
+            )
+            .await
+    }
+
+    /// Get asks (listings)
+    /// @path: /orders/asks/v5
+    #[tracing::instrument(name = "asks", skip(self, request), fields(chain = %self.chain))]
+    pub async fn asks(
+        &self,
+        request: EvmOrdersRequest,
+    ) -> Result<EvmOrdersResponse, MagicedenError> {
+        self.client
+            .get_with_query_from(
+                self.client.evm_api_base(),
+                chain_endpoint!(self.chain, "/orders/asks/v5"),
+                &request,
+            )
+            .await
+    }
+
+    /// Get bids (offers)
+    /// @path: /orders/bids/v6
+    #[tracing::instrument(name = "bids", skip(self, request), fields(chain = %self.chain))]
+    pub async fn bids(
+        &self,
+        request: EvmOrdersRequest,
+    ) -> Result<EvmOrdersResponse, MagicedenError> {
+        self.client
+            .get_with_query_from(
+                self.client.evm_api_base(),
+                chain_endpoint!(self.chain, "/orders/bids/v6"),
+                &request,
+            )
+            .await
+    }
+
+    /// Get activity of a user
+    /// @path: /users/activity/v6
+    #[tracing::instrument(name = "user_activity", skip(self, request), fields(chain = %self.chain))]
+    pub async fn user_activity(
+        &self,
+        request: EvmUserActivityRequest,
+    ) -> Result<EvmUserActivityResponse, MagicedenError> {
+        self.client
+            .get_with_query_from(
+                self.client.evm_api_base(),
+                chain_endpoint!(self.chain, "/users/activity/v6"),
+                &request,
+            )
+            .await
+    }
+
+    /// Get sales
+    /// @path: /sales/v6
+    #[tracing::instrument(name = "sales", skip(self, request), fields(chain = %self.chain))]
+    pub async fn sales(
+        &self,
+        request: EvmSalesRequest,
+    ) -> Result<EvmSalesResponse, MagicedenError> {
+        self.client
+            .get_with_query_from(
+                self.client.evm_api_base(),
+                chain_endpoint!(self.chain, "/sales/v6"),
+                &request,
+            )
+            .await
+    }
+}
@@ -0,0 +1,446 @@
+//! Export of API responses to NDJSON and, with the `csv` and `parquet` features, CSV and Parquet.
+//!
+//! Tabular formats use a fixed set of columns per record type, named after the snake case field
+//! names with nested fields joined by `_` (e.g. `rarity_moonrank_rank`, `extra_img`). Columns are
+//! only ever added, never renamed or reordered. Lists are written as JSON encoded text.
+//! NDJSON keeps the API objects as is, one per line.
+//!
+//! ```no_run
+//! # async fn export() -> Result<(), magiceden_rs::error::MagicedenError> {
+//! use magiceden_rs::{export, types::CollectionListingsRequestArgs, Client};
+//!
+//! let client = Client::new();
+//! let request = CollectionListingsRequestArgs::default().build()?;
+//! let listings = client.collections().listings("okay_bears", request).await?;
+//! export::write_ndjson(std::io::stdout(), &listings)?;
+//! # Ok(())
+//! # }
+//! ```
+use std::io::Write;
+
+use serde::Serialize;
+
+use crate::{
+    error::MagicedenError,
+    types::{Collection, CollectionActivitiy, Listing, MmmPool},
+};
+
+/// Type of the values of a column
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Bool,
+    Int,
+    Float,
+    Text,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Column {
+    pub name: &'static str,
+    pub kind: Kind,
+}
+
+/// Value of a column in a row, every column is nullable
+#[derive(Debug, Clone, PartialEq)]
+pub enum Cell {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Text(String),
+}
+
+impl Cell {
+    #[cfg(feature = "csv")]
+    fn text(&self) -> String {
+        match self {
+            Cell::Null => String::new(),
+            Cell::Bool(value) => value.to_string(),
+            Cell::Int(value) => value.to_string(),
+            Cell::Float(value) => value.to_string(),
+            Cell::Text(value) => value.clone(),
+        }
+    }
+}
+
+impl From<bool> for Cell {
+    fn from(value: bool) -> Self {
+        Cell::Bool(value)
+    }
+}
+
+impl From<i32> for Cell {
+    fn from(value: i32) -> Self {
+        Cell::Int(value.into())
+    }
+}
+
+impl From<i64> for Cell {
+    fn from(value: i64) -> Self {
+        Cell::Int(value)
+    }
+}
+
+impl From<f64> for Cell {
+    fn from(value: f64) -> Self {
+        Cell::Float(value)
+    }
+}
+
+impl From<&str> for Cell {
+    fn from(value: &str) -> Self {
+        Cell::Text(value.to_string())
+    }
+}
+
+impl From<&String> for Cell {
+    fn from(value: &String) -> Self {
+        Cell::Text(value.clone())
+    }
+}
+
+impl<T: Into<Cell>> From<Option<T>> for Cell {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Cell::Null, Into::into)
+    }
+}
+
+/// Record with a stable tabular layout
+pub trait Record: Serialize {
+    /// Columns in the order they are written
+    const COLUMNS: &'static [Column];
+
+    /// One cell per column of [`Record::COLUMNS`]
+    fn cells(&self) -> Vec<Cell>;
+}
+
+const fn column(name: &'static str, kind: Kind) -> Column {
+    Column { name, kind }
+}
+
+/// JSON encoding of `value`, for lists and enums
+fn json<T: Serialize>(value: &T) -> Cell {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::Null) | Err(_) => Cell::Null,
+        Ok(serde_json::Value::String(value)) => Cell::Text(value),
+        Ok(value) => Cell::Text(value.to_string()),
+    }
+}
+
+impl Record for CollectionActivitiy {
+    const COLUMNS: &'static [Column] = &[
+        column("signature", Kind::Text),
+        column("type", Kind::Text),
+        column("source", Kind::Text),
+        column("token_mint", Kind::Text),
+        column("collection_symbol", Kind::Text),
+        column("slot", Kind::Int),
+        column("block_time", Kind::Int),
+        column("buyer", Kind::Text),
+        column("buyer_referral", Kind::Text),
+        column("seller", Kind::Text),
+        column("seller_referral", Kind::Text),
+        column("price", Kind::Float),
+        column("image", Kind::Text),
+    ];
+
+    fn cells(&self) -> Vec<Cell> {
+        vec![
+            (&self.signature).into(),
+            (&self.r#type).into(),
+            (&self.source).into(),
+            self.token_mint.as_ref().into(),
+            self.collection_symbol.as_ref().into(),
+            self.slot.into(),
+            self.block_time.into(),
+            self.buyer.as_ref().into(),
+            (&self.buyer_referral).into(),
+            self.seller.as_ref().into(),
+            self.seller_referral.as_ref().into(),
+            self.price.into(),
+            self.image.as_ref().into(),
+        ]
+    }
+}
+
+impl Record for Listing {
+    const COLUMNS: &'static [Column] = &[
+        column("pda_address", Kind::Text),
+        column("auction_house", Kind::Text),
+        column("token_address", Kind::Text),
+        column("token_mint", Kind::Text),
+        column("seller", Kind::Text),
+        column("seller_referral", Kind::Text),
+        column("token_size", Kind::Int),
+        column("price", Kind::Float),
+        column("expiry", Kind::Int),
+        column("rarity_howrare_rank", Kind::Int),
+        column("rarity_moonrank_rank", Kind::Int),
+        column("rarity_moonrank_absolute_rarity", Kind::Int),
+        column("rarity_moonrank_crawl_complete", Kind::Bool),
+        column("rarity_merarity_rank", Kind::Int),
+        column("rarity_merarity_score", Kind::Float),
+        column("rarity_merarity_total_supply", Kind::Int),
+        column("extra_img", Kind::Text),
+    ];
+
+    fn cells(&self) -> Vec<Cell> {
+        let howrare = self.rarity.howrare.as_ref();
+        let moonrank = self.rarity.moonrank.as_ref();
+        let merarity = self.rarity.merarity.as_ref();
+        vec![
+            (&self.pda_address).into(),
+            (&self.auction_house).into(),
+            (&self.token_address).into(),
+            (&self.token_mint).into(),
+            (&self.seller).into(),
+            self.seller_referral.as_ref().into(),
+            self.token_size.into(),
+            self.price.into(),
+            self.expiry.into(),
+            howrare.map(|howrare| howrare.rank).into(),
+            moonrank.map(|moonrank| moonrank.rank).into(),
+            moonrank.map(|moonrank| moonrank.absolute_rarity).into(),
+            moonrank.map(|moonrank| moonrank.crawl.complete).into(),
+            merarity.and_then(|merarity| merarity.rank).into(),
+            merarity.map(|merarity| merarity.score).into(),
+            merarity.and_then(|merarity| merarity.total_supply).into(),
+            self.extra.img.as_ref().into(),
+        ]
+    }
+}
+
+impl Record for MmmPool {
+    const COLUMNS: &'static [Column] = &[
+        column("pool_key", Kind::Text),
+        column("uuid", Kind::Text),
+        column("pool_type", Kind::Text),
+        column("pool_owner", Kind::Text),
+        column("collection_symbol", Kind::Text),
+        column("collection_name", Kind::Text),
+        column("spot_price", Kind::Float),
+        column("curve_type", Kind::Text),
+        column("curve_delta", Kind::Float),
+        column("reinvest_fulfill_buy", Kind::Bool),
+        column("reinvest_fulfill_sell", Kind::Bool),
+        column("expiry", Kind::Int),
+        column("lp_fee_bp", Kind::Int),
+        column("buyside_creator_royalty_bp", Kind::Int),
+        column("sellside_asset_amount", Kind::Int),
+        column("buyside_payment_amount", Kind::Int),
+        column("buy_orders_amount", Kind::Int),
+        column("cosigner", Kind::Text),
+        column("attributes", Kind::Text),
+        column("blocked_at", Kind::Text),
+        column("mints", Kind::Text),
+        column("collection_seller_fee_basis_points", Kind::Int),
+        column("lp_fee_earned", Kind::Int),
+        column("buy_price_taker", Kind::Float),
+        column("is_mip1", Kind::Bool),
+        column("is_ocp", Kind::Bool),
+        column("updated_at", Kind::Text),
+    ];
+
+    fn cells(&self) -> Vec<Cell> {
+        vec![
+            (&self.pool_key).into(),
+            (&self.uuid).into(),
+            json(&self.pool_type),
+            (&self.pool_owner).into(),
+            (&self.collection_symbol).into(),
+            (&self.collection_name).into(),
+            self.spot_price.into(),
+            json(&self.curve_type),
+            self.curve_delta.into(),
+            self.reinvest_fulfill_buy.into(),
+            self.reinvest_fulfill_sell.into(),
+            self.expiry.into(),
+            self.lp_fee_bp.into(),
+            self.buyside_creator_royalty_bp.into(),
+            self.sellside_asset_amount.into(),
+            self.buyside_payment_amount.into(),
+            self.buy_orders_amount.into(),
+            (&self.cosigner).into(),
+            json(&self.attributes),
+            self.blocked_at.as_ref().into(),
+            json(&self.mints),
+            self.collection_seller_fee_basis_points.into(),
+            self.lp_fee_earned.into(),
+            self.buy_price_taker.into(),
+            self.is_mip1.into(),
+            self.is_ocp.into(),
+            (&self.updated_at).into(),
+        ]
+    }
+}
+
+impl Record for Collection {
+    const COLUMNS: &'static [Column] = &[
+        column("symbol", Kind::Text),
+        column("name", Kind::Text),
+        column("description", Kind::Text),
+        column("image", Kind::Text),
+        column("twitter", Kind::Text),
+        column("discord", Kind::Text),
+        column("website", Kind::Text),
+        column("is_flagged", Kind::Bool),
+        column("flag_message", Kind::Text),
+        column("categories", Kind::Text),
+        column("floor_price", Kind::Float),
+        column("listed_count", Kind::Float),
+        column("avg_price24hr", Kind::Float),
+        column("volume_all", Kind::Float),
+        column("is_badged", Kind::Bool),
+    ];
+
+    fn cells(&self) -> Vec<Cell> {
+        vec![
+            (&self.symbol).into(),
+            (&self.name).into(),
+            (&self.description).into(),
+            (&self.image).into(),
+            self.twitter.as_ref().into(),
+            self.discord.as_ref().into(),
+            self.website.as_ref().into(),
+            self.is_flagged.into(),
+            self.flag_message.as_ref().into(),
+            json(&self.categories),
+            self.floor_price.into(),
+            self.listed_count.into(),
+            self.avg_price24hr.into(),
+            self.volume_all.into(),
+            self.is_badged.into(),
+        ]
+    }
+}
+
+fn export_error<E: std::fmt::Display>(e: E) -> MagicedenError {
+    MagicedenError::Export(e.to_string())
+}
+
+/// Write `records` as CSV with a header row, missing values are empty cells
+#[cfg(feature = "csv")]
+pub fn write_csv<W: Write, R: Record>(writer: W, records: &[R]) -> Result<(), MagicedenError> {
+    let mut writer = csv::Writer::from_writer(writer);
+    writer
+        .write_record(R::COLUMNS.iter().map(|column| column.name))
+        .map_err(export_error)?;
+    for record in records {
+        writer
+            .write_record(record.cells().iter().map(Cell::text))
+            .map_err(export_error)?;
+    }
+    writer.flush().map_err(export_error)
+}
+
+/// Write `records` as newline delimited JSON, one API object per line
+pub fn write_ndjson<W: Write, R: Serialize>(
+    mut writer: W,
+    records: &[R],
+) -> Result<(), MagicedenError> {
+    for record in records {
+        serde_json::to_writer(&mut writer, record).map_err(export_error)?;
+        writer.write_all(b"\n").map_err(export_error)?;
+    }
+    writer.flush().map_err(export_error)
+}
+
+/// Write `records` as a Parquet file with a single row group, every column is optional
+#[cfg(feature = "parquet")]
+pub fn write_parquet<W: Write + Send, R: Record>(
+    writer: W,
+    records: &[R],
+) -> Result<(), MagicedenError> {
+    use std::sync::Arc;
+
+    use parquet::{
+        data_type::{BoolType, ByteArray, ByteArrayType, DoubleType, Int64Type},
+        file::{properties::WriterProperties, writer::SerializedFileWriter},
+        schema::parser::parse_message_type,
+    };
+
+    let fields: Vec<String> = R::COLUMNS
+        .iter()
+        .map(|column| match column.kind {
+            Kind::Bool => format!("OPTIONAL BOOLEAN {};", column.name),
+            Kind::Int => format!("OPTIONAL INT64 {};", column.name),
+            Kind::Float => format!("OPTIONAL DOUBLE {};", column.name),
+            Kind::Text => format!("OPTIONAL BYTE_ARRAY {} (UTF8);", column.name),
+        })
+        .collect();
+    let schema = parse_message_type(&format!("message record {{ {} }}", fields.join(" ")))
+        .map_err(export_error)?;
+
+    let rows: Vec<Vec<Cell>> = records.iter().map(Record::cells).collect();
+    let mut writer = SerializedFileWriter::new(
+        writer,
+        Arc::new(schema),
+        Arc::new(WriterProperties::builder().build()),
+    )
+    .map_err(export_error)?;
+    let mut row_group = writer.next_row_group().map_err(export_error)?;
+
+    for (i, column) in R::COLUMNS.iter().enumerate() {
+        let cells = rows.iter().map(|row| &row[i]);
+        let levels: Vec<i16> = cells
+            .clone()
+            .map(|cell| i16::from(*cell != Cell::Null))
+            .collect();
+        let mut column_writer = row_group
+            .next_column()
+            .map_err(export_error)?
+            .ok_or_else(|| export_error(format!("missing column {}", column.name)))?;
+        match column.kind {
+            Kind::Bool => {
+                let values: Vec<bool> = cells
+                    .filter_map(|cell| match cell {
+                        Cell::Bool(value) => Some(*value),
+                        _ => None,
+                    })
+                    .collect();
+                column_writer
+                    .typed::<BoolType>()
+                    .write_batch(&values, Some(&levels), None)
+            }
+            Kind::Int => {
+                let values: Vec<i64> = cells
+                    .filter_map(|cell| match cell {
+                        Cell::Int(value) => Some(*value),
+                        _ => None,
+                    })
+                    .collect();
+                column_writer
+                    .typed::<Int64Type>()
+                    .write_batch(&values, Some(&levels), None)
+            }
+            Kind::Float => {
+                let values: Vec<f64> = cells
+                    .filter_map(|cell| match cell {
+                        Cell::Float(value) => Some(*value),
+                        _ => None,
+                    })
+                    .collect();
+                column_writer
+                    .typed::<DoubleType>()
+                    .write_batch(&values, Some(&levels), None)
+            }
+            Kind::Text => {
+                let values: Vec<ByteArray> = cells
+                    .filter_map(|cell| match cell {
+                        Cell::Text(value) => Some(ByteArray::from(value.as_str())),
+                        _ => None,
+                    })
+                    .collect();
+                column_writer
+                    .typed::<ByteArrayType>()
+                    .write_batch(&values, Some(&levels), None)
+            }
+        }
+        .map_err(export_error)?;
+        column_writer.close().map_err(export_error)?;
+    }
+
+    row_group.close().map_err(export_error)?;
+    writer.close().map_err(export_error)?;
+    Ok(())
+}
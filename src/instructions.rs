@@ -5,11 +5,42 @@ use crate::{
         InstructionsBuyNowRequest, InstructionsBuyNowTransferNftRequest, InstructionsBuyRequest,
         InstructionsDepositRequest, InstructionsSellCancelRequest,
         InstructionsSellChangePriceRequest, InstructionsSellNowRequest, InstructionsSellRequest,
-        InstructionsWithdrawRequest,
+        InstructionsWithdrawRequest, RequestOptions,
     },
     Client,
 };
 
+/// Instruction requests executed on an auction house
+trait AuctionHouseRequest {
+    fn auction_house_address(&mut self) -> &mut String;
+}
+
+macro_rules! auction_house_request {
+    ($($request:ty),*) => {
+        $(
+            impl AuctionHouseRequest for $request {
+                fn auction_house_address(&mut self) -> &mut String {
+                    &mut self.auction_house_address
+                }
+            }
+        )*
+    };
+}
+
+auction_house_request!(
+    InstructionsBuyRequest,
+    InstructionsBuyNowRequest,
+    InstructionsBuyNowTransferNftRequest,
+    InstructionsBuyCancelRequest,
+    InstructionsBuyChangePriceRequest,
+    InstructionsSellRequest,
+    InstructionsSellChangePriceRequest,
+    InstructionsSellNowRequest,
+    InstructionsSellCancelRequest,
+    InstructionsDepositRequest,
+    InstructionsWithdrawRequest
+);
+
 pub struct Instructions<'c> {
     pub client: &'c Client,
 }
@@ -19,14 +50,49 @@ impl<'c> Instructions<'c> {
         Self { client }
     }
 
+    /// Fill an empty auction house address with the default of the client,
+    /// failing when its cluster has none
+    fn with_default_auction_house<R: AuctionHouseRequest>(
+        &self,
+        mut request: R,
+    ) -> Result<R, MagicedenError> {
+        let address = request.auction_house_address();
+        if address.is_empty() {
+            *address = self
+                .client
+                .auction_house_address()
+                .ok_or_else(|| {
+                    MagicedenError::InvalidArgument(format!(
+                        "no default auction house for {:?}, set the auction house address of the request \
+                         or the client",
+                        self.client.cluster()
+                    ))
+                })?
+                .to_string();
+        }
+        Ok(request)
+    }
+
     /// Get instruction to buy (bid)
     /// @path: /instructions/buy
     pub async fn buy(
         &self,
         request: InstructionsBuyRequest,
     ) -> Result<InstructionResponse, MagicedenError> {
+        self.buy_with_options(request, &RequestOptions::default())
+            .await
+    }
+
+    /// Same as [`Self::buy`] with per-request [`RequestOptions`]
+    #[tracing::instrument(name = "buy", skip(self, request, options))]
+    pub async fn buy_with_options(
+        &self,
+        request: InstructionsBuyRequest,
+        options: &RequestOptions,
+    ) -> Result<InstructionResponse, MagicedenError> {
+        let request = self.with_default_auction_house(request)?;
         self.client
-            .get_with_query("/instructions/buy", &request)
+            .get_with_options("/instructions/buy", &request, options)
             .await
     }
 
@@ -36,8 +102,20 @@ impl<'c> Instructions<'c> {
         &self,
         request: InstructionsBuyNowRequest,
     ) -> Result<InstructionResponse, MagicedenError> {
+        self.buy_now_with_options(request, &RequestOptions::default())
+            .await
+    }
+
+    /// Same as [`Self::buy_now`] with per-request [`RequestOptions`]
+    #[tracing::instrument(name = "buy_now", skip(self, request, options))]
+    pub async fn buy_now_with_options(
+        &self,
+        request: InstructionsBuyNowRequest,
+        options: &RequestOptions,
+    ) -> Result<InstructionResponse, MagicedenError> {
+        let request = self.with_default_auction_house(request)?;
         self.client
-            .get_with_query("/instructions/buy_now", &request)
+            .get_with_options("/instructions/buy_now", &request, options)
             .await
     }
 
@@ -47,8 +125,20 @@ impl<'c> Instructions<'c> {
         &self,
         request: InstructionsBuyNowTransferNftRequest,
     ) -> Result<InstructionResponse, MagicedenError> {
+        self.buy_now_transfer_nft_with_options(request, &RequestOptions::default())
+            .await
+    }
+
+    /// Same as [`Self::buy_now_transfer_nft`] with per-request [`RequestOptions`]
+    #[tracing::instrument(name = "buy_now_transfer_nft", skip(self, request, options))]
+    pub async fn buy_now_transfer_nft_with_options(
+        &self,
+        request: InstructionsBuyNowTransferNftRequest,
+        options: &RequestOptions,
+    ) -> Result<InstructionResponse, MagicedenError> {
+        let request = self.with_default_auction_house(request)?;
         self.client
-            .get_with_query("/instructions/buy_now_transfer_nft", &request)
+            .get_with_options("/instructions/buy_now_transfer_nft", &request, options)
             .await
     }
 
@@ -58,8 +148,20 @@ impl<'c> Instructions<'c> {
         &self,
         request: InstructionsBuyCancelRequest,
     ) -> Result<InstructionResponse, MagicedenError> {
+        self.buy_cancel_with_options(request, &RequestOptions::default())
+            .await
+    }
+
+    /// Same as [`Self::buy_cancel`] with per-request [`RequestOptions`]
+    #[tracing::instrument(name = "buy_cancel", skip(self, request, options))]
+    pub async fn buy_cancel_with_options(
+        &self,
+        request: InstructionsBuyCancelRequest,
+        options: &RequestOptions,
+    ) -> Result<InstructionResponse, MagicedenError> {
+        let request = self.with_default_auction_house(request)?;
         self.client
-            .get_with_query("/instructions/buy_cancel", &request)
+            .get_with_options("/instructions/buy_cancel", &request, options)
             .await
     }
 
@@ -69,8 +171,20 @@ impl<'c> Instructions<'c> {
         &self,
         request: InstructionsBuyChangePriceRequest,
     ) -> Result<InstructionResponse, MagicedenError> {
+        self.buy_change_price_with_options(request, &RequestOptions::default())
+            .await
+    }
+
+    /// Same as [`Self::buy_change_price`] with per-request [`RequestOptions`]
+    #[tracing::instrument(name = "buy_change_price", skip(self, request, options))]
+    pub async fn buy_change_price_with_options(
+        &self,
+        request: InstructionsBuyChangePriceRequest,
+        options: &RequestOptions,
+    ) -> Result<InstructionResponse, MagicedenError> {
+        let request = self.with_default_auction_house(request)?;
         self.client
-            .get_with_query("/instructions/buy_change_price", &request)
+            .get_with_options("/instructions/buy_change_price", &request, options)
             .await
     }
 
@@ -80,8 +194,20 @@ impl<'c> Instructions<'c> {
         &self,
         request: InstructionsSellRequest,
     ) -> Result<InstructionResponse, MagicedenError> {
+        self.sell_with_options(request, &RequestOptions::default())
+            .await
+    }
+
+    /// Same as [`Self::sell`] with per-request [`RequestOptions`]
+    #[tracing::instrument(name = "sell", skip(self, request, options))]
+    pub async fn sell_with_options(
+        &self,
+        request: InstructionsSellRequest,
+        options: &RequestOptions,
+    ) -> Result<InstructionResponse, MagicedenError> {
+        let request = self.with_default_auction_house(request)?;
         self.client
-            .get_with_query("/instructions/sell", &request)
+            .get_with_options("/instructions/sell", &request, options)
             .await
     }
 
@@ -91,8 +217,20 @@ impl<'c> Instructions<'c> {
         &self,
         request: InstructionsSellChangePriceRequest,
     ) -> Result<InstructionResponse, MagicedenError> {
+        self.sell_change_price_with_options(request, &RequestOptions::default())
+            .await
+    }
+
+    /// Same as [`Self::sell_change_price`] with per-request [`RequestOptions`]
+    #[tracing::instrument(name = "sell_change_price", skip(self, request, options))]
+    pub async fn sell_change_price_with_options(
+        &self,
+        request: InstructionsSellChangePriceRequest,
+        options: &RequestOptions,
+    ) -> Result<InstructionResponse, MagicedenError> {
+        let request = self.with_default_auction_house(request)?;
         self.client
-            .get_with_query("/instructions/sell_change_price", &request)
+            .get_with_options("/instructions/sell_change_price", &request, options)
             .await
     }
 
@@ -102,8 +240,20 @@ impl<'c> Instructions<'c> {
         &self,
         request: InstructionsSellNowRequest,
     ) -> Result<InstructionResponse, MagicedenError> {
+        self.sell_now_with_options(request, &RequestOptions::default())
+            .await
+    }
+
+    /// Same as [`Self::sell_now`] with per-request [`RequestOptions`]
+    #[tracing::instrument(name = "sell_now", skip(self, request, options))]
+    pub async fn sell_now_with_options(
+        &self,
+        request: InstructionsSellNowRequest,
+        options: &RequestOptions,
+    ) -> Result<InstructionResponse, MagicedenError> {
+        let request = self.with_default_auction_house(request)?;
         self.client
-            .get_with_query("/instructions/sell_now", &request)
+            .get_with_options("/instructions/sell_now", &request, options)
             .await
     }
 
@@ -113,8 +263,20 @@ impl<'c> Instructions<'c> {
         &self,
         request: InstructionsSellCancelRequest,
     ) -> Result<InstructionResponse, MagicedenError> {
+        self.sell_cancel_with_options(request, &RequestOptions::default())
+            .await
+    }
+
+    /// Same as [`Self::sell_cancel`] with per-request [`RequestOptions`]
+    #[tracing::instrument(name = "sell_cancel", skip(self, request, options))]
+    pub async fn sell_cancel_with_options(
+        &self,
+        request: InstructionsSellCancelRequest,
+        options: &RequestOptions,
+    ) -> Result<InstructionResponse, MagicedenError> {
+        let request = self.with_default_auction_house(request)?;
         self.client
-            .get_with_query("/instructions/sell_cancel", &request)
+            .get_with_options("/instructions/sell_cancel", &request, options)
             .await
     }
 
@@ -124,8 +286,20 @@ impl<'c> Instructions<'c> {
         &self,
         request: InstructionsDepositRequest,
     ) -> Result<InstructionResponse, MagicedenError> {
+        self.deposit_with_options(request, &RequestOptions::default())
+            .await
+    }
+
+    /// Same as [`Self::deposit`] with per-request [`RequestOptions`]
+    #[tracing::instrument(name = "deposit", skip(self, request, options))]
+    pub async fn deposit_with_options(
+        &self,
+        request: InstructionsDepositRequest,
+        options: &RequestOptions,
+    ) -> Result<InstructionResponse, MagicedenError> {
+        let request = self.with_default_auction_house(request)?;
         self.client
-            .get_with_query("/instructions/deposit", &request)
+            .get_with_options("/instructions/deposit", &request, options)
             .await
     }
 
@@ -135,8 +309,20 @@ impl<'c> Instructions<'c> {
         &self,
         request: InstructionsWithdrawRequest,
     ) -> Result<InstructionResponse, MagicedenError> {
+        self.withdraw_with_options(request, &RequestOptions::default())
+            .await
+    }
+
+    /// Same as [`Self::withdraw`] with per-request [`RequestOptions`]
+    #[tracing::instrument(name = "withdraw", skip(self, request, options))]
+    pub async fn withdraw_with_options(
+        &self,
+        request: InstructionsWithdrawRequest,
+        options: &RequestOptions,
+    ) -> Result<InstructionResponse, MagicedenError> {
+        let request = self.with_default_auction_house(request)?;
         self.client
-            .get_with_query("/instructions/withdraw", &request)
+            .get_with_options("/instructions/withdraw", &request, options)
             .await
     }
 }
@@ -0,0 +1,280 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use reqwest::header::{HeaderName, HeaderValue, AUTHORIZATION};
+use serde::Deserialize;
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::error::MagicedenError;
+
+/// API key kept out of logs: `Debug` and `Display` show `***` and the key is zeroized on drop
+#[derive(Clone, PartialEq, Eq)]
+pub struct ApiKey(String);
+
+impl ApiKey {
+    pub fn new<S: Into<String>>(key: S) -> Self {
+        Self(key.into())
+    }
+
+    /// The key itself, to be handled with care
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for ApiKey {
+    fn from(key: String) -> Self {
+        Self(key)
+    }
+}
+
+impl From<&str> for ApiKey {
+    fn from(key: &str) -> Self {
+        Self(key.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ApiKey {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self)
+    }
+}
+
+impl std::fmt::Debug for ApiKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("***")
+    }
+}
+
+impl std::fmt::Display for ApiKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("***")
+    }
+}
+
+impl Drop for ApiKey {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/// How API keys are sent
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum Auth {
+    /// Send no key, requests are unauthenticated
+    None,
+    /// Send the key as `Authorization: Bearer {key}`
+    #[default]
+    Bearer,
+    /// Send the key as is in a custom header, e.g. for a proxy expecting `x-api-key`
+    Header(HeaderName),
+}
+
+impl Auth {
+    /// Header carrying `key`, `None` when keys are not sent
+    pub(crate) fn header(&self, key: &ApiKey) -> Option<(HeaderName, HeaderValue)> {
+        let key = key.expose_secret();
+        let (name, value) = match self {
+            Auth::None => return None,
+            Auth::Bearer => {
+                let bearer = Zeroizing::new(format!("Bearer {key}"));
+                (AUTHORIZATION, HeaderValue::from_str(&bearer))
+            }
+            Auth::Header(name) => (name.clone(), HeaderValue::from_str(key)),
+        };
+        // Keys are validated when added to the pool
+        let mut value = value.ok()?;
+        value.set_sensitive(true);
+        Some((name, value))
+    }
+}
+
+/// How the next key of an [`ApiKeys`] pool is picked
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeySelection {
+    /// Use every key in turn
+    #[default]
+    RoundRobin,
+    /// Use the key that sent the fewest requests
+    LeastUsed,
+}
+
+/// Usage of a key of an [`ApiKeys`] pool
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyUsage {
+    /// Position of the key in the pool
+    pub index: usize,
+    /// Requests sent with the key, retries included
+    pub requests: u64,
+    /// Responses rate limited with a `429`, exceeded quotas aside
+    pub rate_limited: u64,
+    /// Whether the API reported the quota of the key as exceeded.
+    /// Exhausted keys are skipped until a request with the key succeeds again.
+    pub exhausted: bool,
+}
+
+#[derive(Debug)]
+struct Key {
+    key: ApiKey,
+    requests: AtomicU64,
+    rate_limited: AtomicU64,
+    exhausted: AtomicBool,
+    next_slot: Mutex<Option<Instant>>,
+}
+
+/// Pool of API keys shared by every clone of a [`Client`](crate::Client).
+///
+/// Every attempt of a request is sent with the key picked by the [`KeySelection`], skipping keys whose
+/// quota is exceeded; a request failing with `insufficient_quota` is sent again right away with another key.
+#[derive(Debug, Default)]
+pub struct ApiKeys {
+    keys: Vec<Key>,
+    selection: KeySelection,
+    interval: Option<Duration>,
+    cursor: AtomicUsize,
+}
+
+impl ApiKeys {
+    /// Create a pool of `keys`, failing when a key is empty or not a valid header value
+    pub fn new<I, S>(keys: I) -> Result<Self, MagicedenError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<ApiKey>,
+    {
+        let keys = keys
+            .into_iter()
+            .enumerate()
+            .map(|(index, key)| {
+                let key = key.into();
+                let secret = key.expose_secret();
+                if secret.is_empty() || HeaderValue::from_str(secret).is_err() {
+                    // The key itself is left out of the error so it does not end up in logs
+                    return Err(MagicedenError::InvalidArgument(format!(
+                        "API key {index} is empty or contains characters not allowed in a header"
+                    )));
+                }
+                Ok(Key {
+                    key,
+                    requests: AtomicU64::new(0),
+                    rate_limited: AtomicU64::new(0),
+                    exhausted: AtomicBool::new(false),
+                    next_slot: Mutex::new(None),
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            keys,
+            ..Default::default()
+        })
+    }
+
+    pub fn with_selection(mut self, selection: KeySelection) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    /// Limit every key to `requests` per `period`, requests over the limit wait for their turn
+    pub fn with_rate_limit(mut self, requests: u32, period: Duration) -> Self {
+        self.interval = Some(period / requests.max(1));
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    pub fn selection(&self) -> KeySelection {
+        self.selection
+    }
+
+    /// Usage of every key, in pool order
+    pub fn usage(&self) -> Vec<KeyUsage> {
+        self.keys
+            .iter()
+            .enumerate()
+            .map(|(index, key)| KeyUsage {
+                index,
+                requests: key.requests.load(Ordering::Relaxed),
+                rate_limited: key.rate_limited.load(Ordering::Relaxed),
+                exhausted: key.exhausted.load(Ordering::Relaxed),
+            })
+            .collect()
+    }
+
+    pub(crate) fn key(&self, index: usize) -> &ApiKey {
+        &self.keys[index].key
+    }
+
+    /// Pick the key of the next attempt, `None` when the pool is empty.
+    /// Exhausted keys are only picked once every key is exhausted.
+    pub(crate) fn select(&self) -> Option<usize> {
+        if self.keys.is_empty() {
+            return None;
+        }
+        let available = |index: &usize| !self.keys[*index].exhausted.load(Ordering::Relaxed);
+        let requests = |index: &usize| self.keys[*index].requests.load(Ordering::Relaxed);
+        let indexes = 0..self.keys.len();
+
+        match self.selection {
+            KeySelection::RoundRobin => {
+                let start = self.cursor.fetch_add(1, Ordering::Relaxed) % self.keys.len();
+                indexes
+                    .map(|i| (start + i) % self.keys.len())
+                    .find(available)
+                    .or(Some(start))
+            }
+            KeySelection::LeastUsed => indexes
+                .clone()
+                .filter(available)
+                .min_by_key(requests)
+                .or_else(|| indexes.min_by_key(requests)),
+        }
+    }
+
+    /// Wait for the rate limit of the key and count the request
+    pub(crate) async fn acquire(&self, index: usize) {
+        let key = &self.keys[index];
+        if let Some(interval) = self.interval {
+            let wait = {
+                let mut next_slot = key.next_slot.lock().unwrap();
+                let now = Instant::now();
+                let slot = next_slot.map_or(now, |next| next.max(now));
+                *next_slot = Some(slot + interval);
+                slot - now
+            };
+            if !wait.is_zero() {
+                tokio::time::sleep(wait).await;
+            }
+        }
+        key.requests.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn succeeded(&self, index: usize) {
+        self.keys[index].exhausted.store(false, Ordering::Relaxed);
+    }
+
+    pub(crate) fn rate_limited(&self, index: usize) {
+        self.keys[index]
+            .rate_limited
+            .fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Mark the quota of the key as exceeded, returns whether another key is still available
+    pub(crate) fn exhausted(&self, index: usize) -> bool {
+        self.keys[index].exhausted.store(true, Ordering::Relaxed);
+        self.keys
+            .iter()
+            .any(|key| !key.exhausted.load(Ordering::Relaxed))
+    }
+}
@@ -0,0 +1,27 @@
+use crate::{
+    error::MagicedenError,
+    types::{LaunchpadCollectionsRequest, LaunchpadCollectionsResponse},
+    Client,
+};
+
+pub struct Launchpad<'c> {
+    pub client: &'c Client,
+}
+
+impl<'c> Launchpad<'c> {
+    pub fn new(client: &'c Client) -> Self {
+        Self { client }
+    }
+
+    /// Get launchpad collections with their mint stages
+    /// @path: /launchpad/collections
+    #[tracing::instrument(skip(self, request))]
+    pub async fn collections(
+        &self,
+        request: LaunchpadCollectionsRequest,
+    ) -> Result<LaunchpadCollectionsResponse, MagicedenError> {
+        self.client
+            .get_with_query("/launchpad/collections", &request)
+            .await
+    }
+}
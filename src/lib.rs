@@ -1,19 +1,47 @@
+mod bids;
+#[cfg(feature = "blocking")]
+pub mod blocking;
 mod client;
+mod cluster;
 mod collections;
+mod config;
 pub mod error;
+mod evm;
+pub mod export;
 mod instructions;
+mod keys;
+mod launchpad;
 mod marketplace;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+#[cfg(not(feature = "metrics"))]
+mod metrics;
 mod mmm;
+mod ordinals;
+mod reprice;
+#[cfg(feature = "store")]
+pub mod store;
+mod sweep;
 mod tokens;
 pub mod types;
 mod wallets;
+mod watch;
 
+pub use bids::Bids;
 pub use client::Client;
+pub use cluster::{Cluster, AUCTION_HOUSE_ADDRESS, DEVNET_API_BASE, MMM_PROGRAM_ID};
 pub use collections::Collections;
+pub use config::{BackoffConfig, ClientConfig, RateLimitConfig};
+pub use evm::Evm;
 pub use instructions::Instructions;
+pub use keys::{ApiKey, ApiKeys, Auth, KeySelection, KeyUsage};
+pub use launchpad::Launchpad;
 pub use marketplace::Marketplace;
 pub use mmm::Mmm;
+pub use ordinals::Ordinals;
+pub use reprice::Reprice;
+pub use sweep::Sweep;
 pub use tokens::Tokens;
 pub use wallets::Wallets;
 
-pub use client::API_BASE;
+pub use client::{API_BASE, API_KEY_ENV, API_KEY_FILE_ENV, EVM_API_BASE, ORDINALS_API_BASE};
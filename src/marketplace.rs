@@ -1,6 +1,6 @@
 use crate::{
     error::MagicedenError,
-    types::{PopularCollectionsRequest, PopularCollectionsResponse},
+    types::{PopularCollectionsRequest, PopularCollectionsResponse, RequestOptions},
     Client,
 };
 
@@ -18,9 +18,20 @@ impl<'c> Marketplace<'c> {
     pub async fn popular_collections(
         &self,
         request: PopularCollectionsRequest,
+    ) -> Result<PopularCollectionsResponse, MagicedenError> {
+        self.popular_collections_with_options(request, &RequestOptions::default())
+            .await
+    }
+
+    /// Same as [`Self::popular_collections`] with per-request [`RequestOptions`]
+    #[tracing::instrument(name = "popular_collections", skip(self, request, options))]
+    pub async fn popular_collections_with_options(
+        &self,
+        request: PopularCollectionsRequest,
+        options: &RequestOptions,
     ) -> Result<PopularCollectionsResponse, MagicedenError> {
         self.client
-            .get_with_query("/marketplace/popular_collections", &request)
+            .get_with_options("/marketplace/popular_collections", &request, options)
             .await
     }
 }
@@ -0,0 +1,59 @@
+//! Request metrics reported through the [`metrics`](https://docs.rs/metrics) facade.
+//!
+//! Enabled with the `metrics` feature. Nothing is exported by this crate: install any recorder
+//! (Prometheus, StatsD, ...) in the application and the client reports into it. Every metric is
+//! labelled with the `endpoint` template, e.g. `/collections/{symbol}/stats`, so label cardinality
+//! stays bounded regardless of how many collections or wallets are queried.
+#![cfg_attr(not(feature = "metrics"), allow(dead_code, unused_variables))]
+
+use std::time::Duration;
+
+use reqwest::{Method, StatusCode};
+
+/// Counter of attempts that got an answer, labelled with `endpoint`, `method` and `status`.
+/// `status` is `error` when the request failed before a response was received.
+pub const REQUESTS: &str = "magiceden_requests_total";
+/// Counter of retried attempts, labelled with `endpoint`.
+pub const RETRIES: &str = "magiceden_retries_total";
+/// Counter of `429 Too Many Requests` responses, labelled with `endpoint`.
+pub const RATE_LIMITED: &str = "magiceden_rate_limited_total";
+/// Counter of response bodies that could not be deserialized, labelled with `endpoint`.
+pub const DESERIALIZATION_FAILURES: &str = "magiceden_deserialization_failures_total";
+/// Histogram of attempt latency in seconds, labelled with `endpoint` and `method`.
+pub const REQUEST_DURATION: &str = "magiceden_request_duration_seconds";
+
+/// Suggested bucket boundaries in seconds for [`REQUEST_DURATION`], for exporters that need them,
+/// e.g. `PrometheusBuilder::set_buckets_for_metric`.
+pub const LATENCY_BUCKETS: &[f64] = &[0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0];
+
+pub(crate) fn response(
+    endpoint: &'static str,
+    method: &Method,
+    status: Option<StatusCode>,
+    latency: Duration,
+) {
+    #[cfg(feature = "metrics")]
+    {
+        let method = method.to_string();
+        let status = status.map_or_else(|| "error".to_string(), |s| s.as_u16().to_string());
+        ::metrics::counter!(REQUESTS, "endpoint" => endpoint, "method" => method.clone(), "status" => status)
+            .increment(1);
+        ::metrics::histogram!(REQUEST_DURATION, "endpoint" => endpoint, "method" => method)
+            .record(latency.as_secs_f64());
+    }
+}
+
+pub(crate) fn retry(endpoint: &'static str) {
+    #[cfg(feature = "metrics")]
+    ::metrics::counter!(RETRIES, "endpoint" => endpoint).increment(1);
+}
+
+pub(crate) fn rate_limited(endpoint: &'static str) {
+    #[cfg(feature = "metrics")]
+    ::metrics::counter!(RATE_LIMITED, "endpoint" => endpoint).increment(1);
+}
+
+pub(crate) fn deserialization_failure(endpoint: &'static str) {
+    #[cfg(feature = "metrics")]
+    ::metrics::counter!(DESERIALIZATION_FAILURES, "endpoint" => endpoint).increment(1);
+}
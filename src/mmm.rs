@@ -1,9 +1,10 @@
 use crate::{
+    client::endpoint,
     error::MagicedenError,
     types::{
         InstructionResponse, MmmCreatePoolRequest, MmmPoolsRequest, MmmPoolsResponse,
         MmmSolFulfillBuyRequest, MmmSolFulfillSellRequest, MmmSolWithdrawBuyRequest,
-        MmmTokenPoolsRequest, MmmTokenPoolsResponse,
+        MmmTokenPoolsRequest, MmmTokenPoolsResponse, RequestOptions,
     },
     Client,
 };
@@ -23,7 +24,20 @@ impl<'c> Mmm<'c> {
         &self,
         request: MmmPoolsRequest,
     ) -> Result<MmmPoolsResponse, MagicedenError> {
-        self.client.get_with_query("/mmm/pools", &request).await
+        self.pools_with_options(request, &RequestOptions::default())
+            .await
+    }
+
+    /// Same as [`Self::pools`] with per-request [`RequestOptions`]
+    #[tracing::instrument(name = "pools", skip(self, request, options))]
+    pub async fn pools_with_options(
+        &self,
+        request: MmmPoolsRequest,
+        options: &RequestOptions,
+    ) -> Result<MmmPoolsResponse, MagicedenError> {
+        self.client
+            .get_with_options("/mmm/pools", &request, options)
+            .await
     }
 
     /// Get best offers for an NFT
@@ -32,9 +46,21 @@ impl<'c> Mmm<'c> {
         &self,
         mint_address: &str,
         request: MmmTokenPoolsRequest,
+    ) -> Result<MmmTokenPoolsResponse, MagicedenError> {
+        self.token_pools_with_options(mint_address, request, &RequestOptions::default())
+            .await
+    }
+
+    /// Same as [`Self::token_pools`] with per-request [`RequestOptions`]
+    #[tracing::instrument(name = "token_pools", skip(self, request, options))]
+    pub async fn token_pools_with_options(
+        &self,
+        mint_address: &str,
+        request: MmmTokenPoolsRequest,
+        options: &RequestOptions,
     ) -> Result<MmmTokenPoolsResponse, MagicedenError> {
         self.client
-            .get_with_query(&format!("/token/{mint_address}/pools"), &request)
+            .get_with_options(endpoint!("/token/{mint_address}/pools"), &request, options)
             .await
     }
 
@@ -43,9 +69,20 @@ impl<'c> Mmm<'c> {
     pub async fn create_pool(
         &self,
         request: MmmCreatePoolRequest,
+    ) -> Result<InstructionResponse, MagicedenError> {
+        self.create_pool_with_options(request, &RequestOptions::default())
+            .await
+    }
+
+    /// Same as [`Self::create_pool`] with per-request [`RequestOptions`]
+    #[tracing::instrument(name = "create_pool", skip(self, request, options))]
+    pub async fn create_pool_with_options(
+        &self,
+        request: MmmCreatePoolRequest,
+        options: &RequestOptions,
     ) -> Result<InstructionResponse, MagicedenError> {
         self.client
-            .get_with_query("/instructions/mmm/create-pool", &request)
+            .get_with_options("/instructions/mmm/create-pool", &request, options)
             .await
     }
 
@@ -54,9 +91,20 @@ impl<'c> Mmm<'c> {
     pub async fn sol_withdraw_buy(
         &self,
         request: MmmSolWithdrawBuyRequest,
+    ) -> Result<InstructionResponse, MagicedenError> {
+        self.sol_withdraw_buy_with_options(request, &RequestOptions::default())
+            .await
+    }
+
+    /// Same as [`Self::sol_withdraw_buy`] with per-request [`RequestOptions`]
+    #[tracing::instrument(name = "sol_withdraw_buy", skip(self, request, options))]
+    pub async fn sol_withdraw_buy_with_options(
+        &self,
+        request: MmmSolWithdrawBuyRequest,
+        options: &RequestOptions,
     ) -> Result<InstructionResponse, MagicedenError> {
         self.client
-            .get_with_query("/instructions/mmm/sol-withdraw-buy", &request)
+            .get_with_options("/instructions/mmm/sol-withdraw-buy", &request, options)
             .await
     }
 
@@ -65,9 +113,20 @@ impl<'c> Mmm<'c> {
     pub async fn sol_fulfill_buy(
         &self,
         request: MmmSolFulfillBuyRequest,
+    ) -> Result<InstructionResponse, MagicedenError> {
+        self.sol_fulfill_buy_with_options(request, &RequestOptions::default())
+            .await
+    }
+
+    /// Same as [`Self::sol_fulfill_buy`] with per-request [`RequestOptions`]
+    #[tracing::instrument(name = "sol_fulfill_buy", skip(self, request, options))]
+    pub async fn sol_fulfill_buy_with_options(
+        &self,
+        request: MmmSolFulfillBuyRequest,
+        options: &RequestOptions,
     ) -> Result<InstructionResponse, MagicedenError> {
         self.client
-            .get_with_query("/instructions/mmm/sol-fulfill-buy", &request)
+            .get_with_options("/instructions/mmm/sol-fulfill-buy", &request, options)
             .await
     }
 
@@ -76,9 +135,20 @@ impl<'c> Mmm<'c> {
     pub async fn sol_fulfill_sell(
         &self,
         request: MmmSolFulfillSellRequest,
+    ) -> Result<InstructionResponse, MagicedenError> {
+        self.sol_fulfill_sell_with_options(request, &RequestOptions::default())
+            .await
+    }
+
+    /// Same as [`Self::sol_fulfill_sell`] with per-request [`RequestOptions`]
+    #[tracing::instrument(name = "sol_fulfill_sell", skip(self, request, options))]
+    pub async fn sol_fulfill_sell_with_options(
+        &self,
+        request: MmmSolFulfillSellRequest,
+        options: &RequestOptions,
     ) -> Result<InstructionResponse, MagicedenError> {
         self.client
-            .get_with_query("/instructions/mmm/sol-fulfill-sell", &request)
+            .get_with_options("/instructions/mmm/sol-fulfill-sell", &request, options)
             .await
     }
 }
@@ -0,0 +1,135 @@
+use crate::{
+    client::endpoint,
+    error::MagicedenError,
+    types::{
+        OrdinalsActivitiesRequest, OrdinalsActivitiesResponse, OrdinalsCollection,
+        OrdinalsCollectionStatsRequest, OrdinalsCollectionStatsResponse,
+        OrdinalsPopularCollectionsRequest, OrdinalsPopularCollectionsResponse,
+        OrdinalsTokensRequest, OrdinalsTokensResponse, RuneBalanceResponse, RuneMarketInfoResponse,
+        RuneOrdersRequest, RuneOrdersResponse,
+    },
+    Client,
+};
+
+/// Bitcoin Ordinals API group, requests are made against `Client::ordinals_api_base`
+pub struct Ordinals<'c> {
+    pub client: &'c Client,
+}
+
+impl<'c> Ordinals<'c> {
+    pub fn new(client: &'c Client) -> Self {
+        Self { client }
+    }
+
+    /// Get tokens (inscriptions), filtered by ids, collection or owner
+    /// @path: /tokens
+    #[tracing::instrument(name = "tokens", skip(self, request))]
+    pub async fn tokens(
+        &self,
+        request: OrdinalsTokensRequest,
+    ) -> Result<OrdinalsTokensResponse, MagicedenError> {
+        self.client
+            .get_with_query_from(self.client.ordinals_api_base(), "/tokens", &request)
+            .await
+    }
+
+    /// Get activities, filtered by collection, token or owner
+    /// @path: /activities
+    #[tracing::instrument(name = "activities", skip(self, request))]
+    pub async fn activities(
+        &self,
+        request: OrdinalsActivitiesRequest,
+    ) -> Result<OrdinalsActivitiesResponse, MagicedenError> {
+        self.client
+            .get_with_query_from(self.client.ordinals_api_base(), "/activities", &request)
+            .await
+    }
+
+    /// Get a collection by symbol
+    /// @path: /collections/{symbol}
+    #[tracing::instrument(name = "collection", skip(self))]
+    pub async fn collection(&self, symbol: &str) -> Result<OrdinalsCollection, MagicedenError> {
+        self.client
+            .get_from(
+                self.client.ordinals_api_base(),
+                endpoint!("/collections/{symbol}"),
+            )
+            .await
+    }
+
+    /// Get stats of a collection
+    /// @path: /stat
+    #[tracing::instrument(name = "collection_stats", skip(self, request))]
+    pub async fn collection_stats(
+        &self,
+        request: OrdinalsCollectionStatsRequest,
+    ) -> Result<OrdinalsCollectionStatsResponse, MagicedenError> {
+        self.client
+            .get_with_query_from(self.client.ordinals_api_base(), "/stat", &request)
+            .await
+    }
+
+    /// Get popular collections for a time window
+    /// @path: /popular_collections
+    #[tracing::instrument(name = "popular_collections", skip(self, request))]
+    pub async fn popular_collections(
+        &self,
+        request: OrdinalsPopularCollectionsRequest,
+    ) -> Result<OrdinalsPopularCollectionsResponse, MagicedenError> {
+        self.client
+            .get_with_query_from(
+                self.client.ordinals_api_base(),
+                "/popular_collections",
+                &request,
+            )
+            .await
+    }
+
+    /// Get market info of a rune
+    /// @path: /runes/market/{rune}/info
+    #[tracing::instrument(name = "rune_market_info", skip(self))]
+    pub async fn rune_market_info(
+        &self,
+        rune: &str,
+    ) -> Result<RuneMarketInfoResponse, MagicedenError> {
+        self.client
+            .get_from(
+                self.client.ordinals_api_base(),
+                endpoint!("/runes/market/{rune}/info"),
+            )
+            .await
+    }
+
+    /// Get orders of a rune
+    /// @path: /runes/orders/{rune}
+    #[tracing::instrument(name = "rune_orders", skip(self, request))]
+    pub async fn rune_orders(
+        &self,
+        rune: &str,
+        request: RuneOrdersRequest,
+    ) -> Result<RuneOrdersResponse, MagicedenError> {
+        self.client
+            .get_with_query_from(
+                self.client.ordinals_api_base(),
+                endpoint!("/runes/orders/{rune}"),
+                &request,
+            )
+            .await
+    }
+
+    /// Get the balance of a rune held by a wallet
+    /// @path: /runes/wallet/balances/{address}/{rune}
+    #[tracing::instrument(name = "rune_balance", skip(self))]
+    pub async fn rune_balance(
+        &self,
+        address: &str,
+        rune: &str,
+    ) -> Result<RuneBalanceResponse, MagicedenError> {
+        self.client
+            .get_from(
+                self.client.ordinals_api_base(),
+                endpoint!("/runes/wallet/balances/{address}/{rune}"),
+            )
+            .await
+    }
+}
@@ -0,0 +1,156 @@
+use futures::{stream, StreamExt, TryStreamExt};
+
+use crate::{
+    error::MagicedenError,
+    types::{
+        InstructionResponse, InstructionsSellCancelRequestArgs,
+        InstructionsSellChangePriceRequestArgs, InstructionsSellRequestArgs, Listing, MarketPrices,
+        PricingRule, RepriceAction, RepriceChange, RepriceInstruction, RepricePlan, RepriceRequest,
+    },
+    Client,
+};
+
+const CONCURRENCY: usize = 4;
+
+/// Brings the listings of a seller wallet in line with a `PricingRule` using
+/// `Instructions::sell`, `Instructions::sell_change_price` and `Instructions::sell_cancel`
+pub struct Reprice<'c> {
+    pub client: &'c Client,
+}
+
+impl<'c> Reprice<'c> {
+    pub fn new(client: &'c Client) -> Self {
+        Self { client }
+    }
+
+    /// Fetch the current listings and market prices and diff them with the rule (dry-run)
+    pub async fn plan(&self, request: &RepriceRequest) -> Result<RepricePlan, MagicedenError> {
+        let market = self.market(request).await?;
+
+        let tokens = self.client.tokens();
+        let listings: Vec<Vec<Listing>> = stream::iter(&request.items)
+            .map(|item| tokens.listings(&item.token_mint))
+            .buffered(CONCURRENCY)
+            .try_collect()
+            .await?;
+        let listings: Vec<Listing> = listings.into_iter().flatten().collect();
+
+        Ok(request.diff(&listings, &market))
+    }
+
+    /// Request the instructions for every actionable change of `plan`
+    pub async fn instructions(
+        &self,
+        request: &RepriceRequest,
+        plan: &RepricePlan,
+    ) -> Result<Vec<RepriceInstruction>, MagicedenError> {
+        stream::iter(plan.actionable())
+            .map(|change| async {
+                let instruction = self.instruction(request, change).await?;
+                Ok(RepriceInstruction {
+                    change: change.clone(),
+                    instruction,
+                })
+            })
+            .buffered(CONCURRENCY)
+            .try_collect()
+            .await
+    }
+
+    async fn market(&self, request: &RepriceRequest) -> Result<MarketPrices, MagicedenError> {
+        let collections = self.client.collections();
+        let mut market = MarketPrices::default();
+
+        match request.rule {
+            Some(PricingRule::BelowFloor { .. }) => {
+                market.floor_price = collections
+                    .stats(&request.collection_symbol)
+                    .await?
+                    .floor_price_sol();
+            }
+            Some(PricingRule::BelowTraitFloor { .. }) => {
+                market.attributes = Some(
+                    collections
+                        .attributes(&request.collection_symbol)
+                        .await?
+                        .results,
+                );
+            }
+            Some(PricingRule::Fixed { .. }) | Some(PricingRule::Delist) => {}
+            None => {
+                return Err(MagicedenError::InvalidArgument(
+                    "missing pricing rule".to_string(),
+                ))
+            }
+        }
+
+        Ok(market)
+    }
+
+    async fn instruction(
+        &self,
+        request: &RepriceRequest,
+        change: &RepriceChange,
+    ) -> Result<InstructionResponse, MagicedenError> {
+        let instructions = self.client.instructions();
+        let item = &change.item;
+        let auction_house = change
+            .listing
+            .as_ref()
+            .map_or(request.auction_house_address.as_str(), |l| {
+                l.auction_house.as_str()
+            });
+
+        match &change.action {
+            RepriceAction::List { price } => {
+                let mut args = InstructionsSellRequestArgs::default();
+                args.seller(request.seller.as_str())
+                    .auction_house_address(auction_house)
+                    .token_mint(item.token_mint.as_str())
+                    .token_account(item.token_account.as_str())
+                    .price(*price);
+                if let Some(seller_referral) = &request.seller_referral {
+                    args.seller_referral(seller_referral.as_str());
+                }
+                if let Some(expiry) = request.expiry {
+                    args.expiry(expiry);
+                }
+                instructions.sell(args.build()?).await
+            }
+            RepriceAction::ChangePrice { old, new } => {
+                let mut args = InstructionsSellChangePriceRequestArgs::default();
+                args.seller(request.seller.as_str())
+                    .auction_house_address(auction_house)
+                    .token_mint(item.token_mint.as_str())
+                    .token_account(item.token_account.as_str())
+                    .price(*old)
+                    .new_price(*new);
+                if let Some(seller_referral) = &request.seller_referral {
+                    args.seller_referral(seller_referral.as_str());
+                }
+                if let Some(expiry) = request.expiry {
+                    args.expiry(expiry);
+                }
+                instructions.sell_change_price(args.build()?).await
+            }
+            RepriceAction::Cancel { price } => {
+                let mut args = InstructionsSellCancelRequestArgs::default();
+                args.seller(request.seller.as_str())
+                    .auction_house_address(auction_house)
+                    .token_mint(item.token_mint.as_str())
+                    .token_account(item.token_account.as_str())
+                    .price(*price);
+                if let Some(seller_referral) = &request.seller_referral {
+                    args.seller_referral(seller_referral.as_str());
+                }
+                instructions.sell_cancel(args.build()?).await
+            }
+            RepriceAction::Keep { .. } | RepriceAction::Skip { .. } => {
+                Err(MagicedenError::InvalidArgument(format!(
+                    "no instruction for {:?} of {}",
+                    change.action, item.token_mint
+                )))
+            }
+        }
+    }
+}
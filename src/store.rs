@@ -0,0 +1,382 @@
+//! SQLite persistence of collection activities, listings, stats and AMM pools, available with the `store` feature.
+//!
+//! Rows are upserted by their natural key (activity `signature`, listing `pda_address`, pool `pool_key`),
+//! so the same page can be saved any number of times. Stats are kept as snapshots keyed by
+//! collection and observation time and back the historical floor and listing count queries.
+//! Each row also keeps the full API object as JSON.
+use std::path::Path;
+
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    error::MagicedenError,
+    types::{CollectionActivitiy, CollectionStatsResponse, Listing, MmmPool, LAMPORTS_PER_SOL},
+};
+
+/// Activity type of a sale
+pub const SALE_ACTIVITY: &str = "buyNow";
+
+/// Schema migrations, the schema version of a database is the number of migrations applied to it
+const MIGRATIONS: &[&str] = &[r#"
+CREATE TABLE activities (
+    signature TEXT PRIMARY KEY,
+    collection_symbol TEXT NOT NULL,
+    type TEXT NOT NULL,
+    token_mint TEXT,
+    buyer TEXT,
+    seller TEXT,
+    price REAL NOT NULL,
+    slot INTEGER NOT NULL,
+    block_time INTEGER NOT NULL,
+    data TEXT NOT NULL
+);
+CREATE INDEX activities_collection_time ON activities (collection_symbol, block_time);
+
+CREATE TABLE listings (
+    pda_address TEXT PRIMARY KEY,
+    collection_symbol TEXT NOT NULL,
+    token_mint TEXT NOT NULL,
+    seller TEXT NOT NULL,
+    price REAL NOT NULL,
+    expiry INTEGER NOT NULL,
+    first_seen INTEGER NOT NULL,
+    last_seen INTEGER NOT NULL,
+    data TEXT NOT NULL
+);
+CREATE INDEX listings_collection ON listings (collection_symbol, last_seen);
+
+CREATE TABLE collection_stats (
+    symbol TEXT NOT NULL,
+    observed_at INTEGER NOT NULL,
+    floor_price REAL,
+    listed_count INTEGER,
+    volume_all REAL,
+    data TEXT NOT NULL,
+    PRIMARY KEY (symbol, observed_at)
+);
+
+CREATE TABLE pools (
+    pool_key TEXT PRIMARY KEY,
+    collection_symbol TEXT NOT NULL,
+    pool_owner TEXT NOT NULL,
+    spot_price REAL NOT NULL,
+    updated_at TEXT NOT NULL,
+    observed_at INTEGER NOT NULL,
+    data TEXT NOT NULL
+);
+CREATE INDEX pools_collection ON pools (collection_symbol);
+"#];
+
+pub struct Store {
+    conn: Connection,
+}
+
+impl Store {
+    /// Open or create the database at `path` and migrate it to the latest schema
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, MagicedenError> {
+        Self::new(Connection::open(path)?)
+    }
+
+    /// In-memory database, mostly useful for tests
+    pub fn open_in_memory() -> Result<Self, MagicedenError> {
+        Self::new(Connection::open_in_memory()?)
+    }
+
+    /// Wrap an existing connection and migrate it to the latest schema
+    pub fn new(mut conn: Connection) -> Result<Self, MagicedenError> {
+        migrate(&mut conn)?;
+        Ok(Self { conn })
+    }
+
+    /// Schema version supported by this version of the crate
+    pub fn schema_version() -> usize {
+        MIGRATIONS.len()
+    }
+
+    /// Underlying connection, for queries not covered by the helpers
+    pub fn connection(&self) -> &Connection {
+        &self.conn
+    }
+
+    /// Upsert activities of a collection by signature, returns the number of rows written
+    pub fn save_activities(
+        &mut self,
+        symbol: &str,
+        activities: &[CollectionActivitiy],
+    ) -> Result<usize, MagicedenError> {
+        let tx = self.conn.transaction()?;
+        let mut written = 0;
+        {
+            let mut statement = tx.prepare(
+                "INSERT INTO activities
+                    (signature, collection_symbol, type, token_mint, buyer, seller, price, slot, block_time, data)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
+                 ON CONFLICT (signature) DO UPDATE SET
+                    collection_symbol = excluded.collection_symbol, type = excluded.type,
+                    token_mint = excluded.token_mint, buyer = excluded.buyer, seller = excluded.seller,
+                    price = excluded.price, slot = excluded.slot, block_time = excluded.block_time,
+                    data = excluded.data",
+            )?;
+            for activity in activities {
+                written += statement.execute(params![
+                    activity.signature,
+                    symbol,
+                    activity.r#type,
+                    activity.token_mint,
+                    activity.buyer,
+                    activity.seller,
+                    activity.price,
+                    activity.slot,
+                    activity.block_time,
+                    to_json(activity)?,
+                ])?;
+            }
+        }
+        tx.commit()?;
+        Ok(written)
+    }
+
+    /// Upsert listings of a collection seen at `observed_at` (unix seconds) by pda address.
+    ///
+    /// `first_seen` is kept from the first save, `last_seen` and the listing itself are updated.
+    pub fn save_listings(
+        &mut self,
+        symbol: &str,
+        listings: &[Listing],
+        observed_at: i64,
+    ) -> Result<usize, MagicedenError> {
+        let tx = self.conn.transaction()?;
+        let mut written = 0;
+        {
+            let mut statement = tx.prepare(
+                "INSERT INTO listings
+                    (pda_address, collection_symbol, token_mint, seller, price, expiry, first_seen, last_seen, data)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?7, ?8)
+                 ON CONFLICT (pda_address) DO UPDATE SET
+                    collection_symbol = excluded.collection_symbol, token_mint = excluded.token_mint,
+                    seller = excluded.seller, price = excluded.price, expiry = excluded.expiry,
+                    last_seen = MAX(last_seen, excluded.last_seen), data = excluded.data",
+            )?;
+            for listing in listings {
+                written += statement.execute(params![
+                    listing.pda_address,
+                    symbol,
+                    listing.token_mint,
+                    listing.seller,
+                    listing.price,
+                    listing.expiry,
+                    observed_at,
+                    to_json(listing)?,
+                ])?;
+            }
+        }
+        tx.commit()?;
+        Ok(written)
+    }
+
+    /// Record a stats snapshot observed at `observed_at` (unix seconds)
+    pub fn save_stats(
+        &mut self,
+        stats: &CollectionStatsResponse,
+        observed_at: i64,
+    ) -> Result<usize, MagicedenError> {
+        Ok(self.conn.execute(
+            "INSERT INTO collection_stats (symbol, observed_at, floor_price, listed_count, volume_all, data)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+             ON CONFLICT (symbol, observed_at) DO UPDATE SET
+                floor_price = excluded.floor_price, listed_count = excluded.listed_count,
+                volume_all = excluded.volume_all, data = excluded.data",
+            params![
+                stats.symbol,
+                observed_at,
+                stats.floor_price,
+                stats.listed_count,
+                stats.volume_all,
+                to_json(stats)?,
+            ],
+        )?)
+    }
+
+    /// Upsert pools seen at `observed_at` (unix seconds) by pool key
+    pub fn save_pools(
+        &mut self,
+        pools: &[MmmPool],
+        observed_at: i64,
+    ) -> Result<usize, MagicedenError> {
+        let tx = self.conn.transaction()?;
+        let mut written = 0;
+        {
+            let mut statement = tx.prepare(
+                "INSERT INTO pools
+                    (pool_key, collection_symbol, pool_owner, spot_price, updated_at, observed_at, data)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+                 ON CONFLICT (pool_key) DO UPDATE SET
+                    collection_symbol = excluded.collection_symbol, pool_owner = excluded.pool_owner,
+                    spot_price = excluded.spot_price, updated_at = excluded.updated_at,
+                    observed_at = excluded.observed_at, data = excluded.data",
+            )?;
+            for pool in pools {
+                written += statement.execute(params![
+                    pool.pool_key,
+                    pool.collection_symbol,
+                    pool.pool_owner,
+                    pool.spot_price,
+                    pool.updated_at,
+                    observed_at,
+                    to_json(pool)?,
+                ])?;
+            }
+        }
+        tx.commit()?;
+        Ok(written)
+    }
+
+    /// Activities of a collection since `since` (unix seconds), oldest first
+    pub fn activities(
+        &self,
+        symbol: &str,
+        since: i64,
+    ) -> Result<Vec<CollectionActivitiy>, MagicedenError> {
+        self.load(
+            "SELECT data FROM activities WHERE collection_symbol = ?1 AND block_time >= ?2
+             ORDER BY block_time, signature",
+            params![symbol, since],
+        )
+    }
+
+    /// Listings of a collection still seen at or after `since` (unix seconds), cheapest first
+    pub fn listings(&self, symbol: &str, since: i64) -> Result<Vec<Listing>, MagicedenError> {
+        self.load(
+            "SELECT data FROM listings WHERE collection_symbol = ?1 AND last_seen >= ?2
+             ORDER BY price, pda_address",
+            params![symbol, since],
+        )
+    }
+
+    /// Pool by pool key
+    pub fn pool(&self, pool_key: &str) -> Result<Option<MmmPool>, MagicedenError> {
+        let data: Option<String> = self
+            .conn
+            .query_row(
+                "SELECT data FROM pools WHERE pool_key = ?1",
+                params![pool_key],
+                |row| row.get(0),
+            )
+            .optional()?;
+        data.map(|data| from_json(&data)).transpose()
+    }
+
+    /// Pools of a collection, cheapest spot price first
+    pub fn pools(&self, symbol: &str) -> Result<Vec<MmmPool>, MagicedenError> {
+        self.load(
+            "SELECT data FROM pools WHERE collection_symbol = ?1 ORDER BY spot_price, pool_key",
+            params![symbol],
+        )
+    }
+
+    /// `(observed_at, floor price in SOL)` of the stats snapshots since `since`, oldest first
+    pub fn floor_history(
+        &self,
+        symbol: &str,
+        since: i64,
+    ) -> Result<Vec<(i64, f64)>, MagicedenError> {
+        let mut statement = self.conn.prepare(
+            "SELECT observed_at, floor_price FROM collection_stats
+             WHERE symbol = ?1 AND observed_at >= ?2 AND floor_price IS NOT NULL
+             ORDER BY observed_at",
+        )?;
+        let rows = statement.query_map(params![symbol, since], |row| {
+            Ok((row.get(0)?, row.get::<_, f64>(1)? / LAMPORTS_PER_SOL))
+        })?;
+        Ok(rows.collect::<Result<_, _>>()?)
+    }
+
+    /// `(observed_at, listed count)` of the stats snapshots since `since`, oldest first
+    pub fn listing_count_history(
+        &self,
+        symbol: &str,
+        since: i64,
+    ) -> Result<Vec<(i64, i64)>, MagicedenError> {
+        let mut statement = self.conn.prepare(
+            "SELECT observed_at, listed_count FROM collection_stats
+             WHERE symbol = ?1 AND observed_at >= ?2 AND listed_count IS NOT NULL
+             ORDER BY observed_at",
+        )?;
+        let rows =
+            statement.query_map(params![symbol, since], |row| Ok((row.get(0)?, row.get(1)?)))?;
+        Ok(rows.collect::<Result<_, _>>()?)
+    }
+
+    /// Sum of the sale prices in SOL of a collection with a block time in `[from, to)`
+    pub fn volume(&self, symbol: &str, from: i64, to: i64) -> Result<f64, MagicedenError> {
+        Ok(self.conn.query_row(
+            "SELECT COALESCE(SUM(price), 0) FROM activities
+             WHERE collection_symbol = ?1 AND type = ?2 AND block_time >= ?3 AND block_time < ?4",
+            params![symbol, SALE_ACTIVITY, from, to],
+            |row| row.get(0),
+        )?)
+    }
+
+    /// `(bucket start, volume in SOL)` of the sales since `since` grouped in buckets of `bucket` seconds,
+    /// oldest first, buckets without sales are omitted
+    pub fn volume_history(
+        &self,
+        symbol: &str,
+        since: i64,
+        bucket: i64,
+    ) -> Result<Vec<(i64, f64)>, MagicedenError> {
+        if bucket <= 0 {
+            return Err(MagicedenError::InvalidArgument(
+                "bucket must be positive".to_string(),
+            ));
+        }
+        let mut statement = self.conn.prepare(
+            "SELECT (block_time / ?3) * ?3 AS bucket, SUM(price) FROM activities
+             WHERE collection_symbol = ?1 AND type = ?2 AND block_time >= ?4
+             GROUP BY bucket ORDER BY bucket",
+        )?;
+        let rows = statement.query_map(params![symbol, SALE_ACTIVITY, bucket, since], |row| {
+            Ok((row.get(0)?, row.get(1)?))
+        })?;
+        Ok(rows.collect::<Result<_, _>>()?)
+    }
+
+    fn load<T: DeserializeOwned, P: rusqlite::Params>(
+        &self,
+        sql: &str,
+        params: P,
+    ) -> Result<Vec<T>, MagicedenError> {
+        let mut statement = self.conn.prepare(sql)?;
+        let rows = statement.query_map(params, |row| row.get::<_, String>(0))?;
+        rows.map(|data| from_json(&data?)).collect()
+    }
+}
+
+fn migrate(conn: &mut Connection) -> Result<(), MagicedenError> {
+    let version: usize = conn.query_row("PRAGMA user_version", [], |row| row.get(0))?;
+    if version > MIGRATIONS.len() {
+        return Err(MagicedenError::InvalidArgument(format!(
+            "store schema version {version} is newer than the supported version {}",
+            MIGRATIONS.len()
+        )));
+    }
+
+    for (i, migration) in MIGRATIONS.iter().enumerate().skip(version) {
+        let tx = conn.transaction()?;
+        tx.execute_batch(migration)?;
+        tx.pragma_update(None, "user_version", i + 1)?;
+        tx.commit()?;
+    }
+    Ok(())
+}
+
+/// Serialize a value stored as JSON, failures are reported as a value SQLite cannot store
+fn to_json<T: Serialize>(value: &T) -> Result<String, MagicedenError> {
+    serde_json::to_string(value)
+        .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)).into())
+}
+
+fn from_json<T: DeserializeOwned>(data: &str) -> Result<T, MagicedenError> {
+    serde_json::from_str(data).map_err(MagicedenError::JSONDeserialize)
+}
@@ -0,0 +1,125 @@
+use futures::{stream, StreamExt};
+
+use crate::{
+    error::MagicedenError,
+    types::{
+        CollectionListingsRequestArgs, InstructionResponse, InstructionsBuyNowRequestArgs, Listing,
+        SkipReason, SkippedListing, SweepItem, SweepPlan, SweepRequest, LAMPORTS_PER_SOL,
+    },
+    Client,
+};
+
+const LISTINGS_PAGE_SIZE: i64 = 100;
+const DEFAULT_MAX_LISTINGS: i64 = 500;
+const DEFAULT_CONCURRENCY: usize = 4;
+
+/// Plans sweeps of a collection on top of `Collections::listings` and `Instructions::buy_now`
+pub struct Sweep<'c> {
+    pub client: &'c Client,
+}
+
+impl<'c> Sweep<'c> {
+    pub fn new(client: &'c Client) -> Self {
+        Self { client }
+    }
+
+    /// Fetch the cheapest listings of the collection, select those eligible within budget
+    /// and request a buy now instruction for each of them
+    pub async fn plan(&self, request: SweepRequest) -> Result<SweepPlan, MagicedenError> {
+        let listings = self.listings(&request).await?;
+        let (selected, mut skipped) = request.select(listings);
+
+        let concurrency = request.concurrency.unwrap_or(DEFAULT_CONCURRENCY).max(1);
+        let results: Vec<(Listing, Result<_, MagicedenError>)> = stream::iter(selected)
+            .map(|listing| async {
+                let instruction = self.buy_now(&request, &listing).await;
+                (listing, instruction)
+            })
+            .buffered(concurrency)
+            .collect()
+            .await;
+
+        let mut items = vec![];
+        let mut total_lamports = 0;
+        for (listing, instruction) in results {
+            match instruction {
+                Ok(instruction) => {
+                    total_lamports += (listing.price * LAMPORTS_PER_SOL).round() as i64;
+                    items.push(SweepItem {
+                        listing,
+                        instruction,
+                    });
+                }
+                Err(e) => skipped.push(SkippedListing {
+                    listing,
+                    reason: SkipReason::InstructionFailed(e.to_string()),
+                }),
+            }
+        }
+
+        Ok(SweepPlan {
+            items,
+            total_cost: total_lamports as f64 / LAMPORTS_PER_SOL,
+            skipped,
+        })
+    }
+
+    async fn listings(&self, request: &SweepRequest) -> Result<Vec<Listing>, MagicedenError> {
+        let max_listings = request.max_listings.unwrap_or(DEFAULT_MAX_LISTINGS);
+        let max_price = request
+            .max_price
+            .map_or(request.budget, |max| max.min(request.budget));
+
+        let mut listings = vec![];
+        let mut offset = 0;
+        while offset < max_listings {
+            let mut args = CollectionListingsRequestArgs::default();
+            args.offset(offset)
+                .limit(LISTINGS_PAGE_SIZE.min(max_listings - offset))
+                .max_price(max_price)
+                .sort("listPrice")
+                .sort_direction("asc");
+            if let Some(attributes) = &request.attributes {
+                args.attributes(attributes.clone());
+            }
+
+            let page = self
+                .client
+                .collections()
+                .listings(&request.collection_symbol, args.build()?)
+                .await?;
+            let len = page.len() as i64;
+            listings.extend(page);
+
+            if len < LISTINGS_PAGE_SIZE {
+                break;
+            }
+            offset += len;
+        }
+
+        Ok(listings)
+    }
+
+    async fn buy_now(
+        &self,
+        request: &SweepRequest,
+        listing: &Listing,
+    ) -> Result<InstructionResponse, MagicedenError> {
+        let mut args = InstructionsBuyNowRequestArgs::default();
+        args.buyer(request.buyer.as_str())
+            .seller(listing.seller.as_str())
+            .auction_house_address(listing.auction_house.as_str())
+            .token_mint(listing.token_mint.as_str())
+            .token_ata(listing.token_address.as_str())
+            .price(listing.price)
+            .seller_expiry(listing.expiry);
+        if let Some(buyer_referral) = &request.buyer_referral {
+            args.buyer_referral(buyer_referral.as_str());
+        }
+        if let Some(seller_referral) = &listing.seller_referral {
+            args.seller_referral(seller_referral.as_str());
+        }
+
+        self.client.instructions().buy_now(args.build()?).await
+    }
+}
@@ -1,4 +1,9 @@
-use crate::{error::MagicedenError, types::TokenListingsResponse, Client};
+use crate::{
+    client::endpoint,
+    error::MagicedenError,
+    types::{RequestOptions, TokenListingsResponse, TokenOffersReceivedResponse},
+    Client,
+};
 
 pub struct Tokens<'c> {
     pub client: &'c Client,
@@ -14,14 +19,48 @@ impl<'c> Tokens<'c> {
     pub async fn listings(
         &self,
         token_mint: &str,
+    ) -> Result<TokenListingsResponse, MagicedenError> {
+        self.listings_with_options(token_mint, &RequestOptions::default())
+            .await
+    }
+
+    /// Same as [`Self::listings`] with per-request [`RequestOptions`]
+    #[tracing::instrument(name = "listings", skip(self, options))]
+    pub async fn listings_with_options(
+        &self,
+        token_mint: &str,
+        options: &RequestOptions,
     ) -> Result<TokenListingsResponse, MagicedenError> {
         self.client
-            .get(&format!("/tokens/{token_mint}/listings"))
+            .get_with_options(endpoint!("/tokens/{token_mint}/listings"), &(), options)
+            .await
+    }
+
+    /// Get received offers for a token
+    /// @path: /tokens/{token_mint}/offers_received
+    pub async fn offers_received(
+        &self,
+        token_mint: &str,
+    ) -> Result<TokenOffersReceivedResponse, MagicedenError> {
+        self.offers_received_with_options(token_mint, &RequestOptions::default())
             .await
     }
 
-    // Get received offers for a token
-    // @path: /tokens/{token_mint}/offers_received
+    /// Same as [`Self::offers_received`] with per-request [`RequestOptions`]
+    #[tracing::instrument(name = "offers_received", skip(self, options))]
+    pub async fn offers_received_with_options(
+        &self,
+        token_mint: &str,
+        options: &RequestOptions,
+    ) -> Result<TokenOffersReceivedResponse, MagicedenError> {
+        self.client
+            .get_with_options(
+                endpoint!("/tokens/{token_mint}/offers_received"),
+                &(),
+                options,
+            )
+            .await
+    }
 
     // Get activities for a token
     // @path: /tokens/{token_mint}/activities
@@ -0,0 +1,326 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{InstructionResponse, Offer, LAMPORTS_PER_SOL};
+
+/// Default amount in SOL a bid is placed above the best competing offer
+pub const DEFAULT_BID_INCREMENT: f64 = 0.01;
+
+/// Bid the manager should maintain on a token
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BidTarget {
+    /// Token mint address.
+    pub token_mint: String,
+    /// Price in SOL bid when there is no competing offer
+    pub min_price: f64,
+    /// Never bid above this price in SOL
+    pub max_price: f64,
+}
+
+impl BidTarget {
+    pub fn new<S: Into<String>>(token_mint: S, min_price: f64, max_price: f64) -> Self {
+        Self {
+            token_mint: token_mint.into(),
+            min_price,
+            max_price,
+        }
+    }
+}
+
+/// Bid placed by the manager
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActiveBid {
+    /// Token mint address.
+    pub token_mint: String,
+    /// Price in SOL.
+    pub price: f64,
+    /// Timestamp in seconds the bid expires at, 0 means no expiry
+    pub expiry: i64,
+}
+
+/// Maintains a set of standing bids of a buyer.
+///
+/// The manager is serializable so it can be persisted and resumed after a restart:
+/// after the instructions of a `BidPlan` are sent, record them with [`BidManager::apply`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BidManager {
+    /// Buyer wallet.
+    pub buyer: String,
+    /// Auction house.
+    pub auction_house_address: String,
+    /// Bids to maintain
+    pub targets: Vec<BidTarget>,
+    /// Bids currently placed by the buyer
+    pub bids: Vec<ActiveBid>,
+    /// Amount in SOL bids are placed above the best competing offer
+    pub increment: f64,
+    /// Bid expiry in seconds from now, `None` uses the API default of 7 days
+    pub bid_duration: Option<i64>,
+    /// Withdraw escrow not needed to back the bids
+    pub withdraw_excess: bool,
+}
+
+impl BidManager {
+    pub fn new<B: Into<String>, A: Into<String>>(buyer: B, auction_house_address: A) -> Self {
+        Self {
+            buyer: buyer.into(),
+            auction_house_address: auction_house_address.into(),
+            targets: vec![],
+            bids: vec![],
+            increment: DEFAULT_BID_INCREMENT,
+            bid_duration: None,
+            withdraw_excess: false,
+        }
+    }
+
+    pub fn with_targets(mut self, targets: Vec<BidTarget>) -> Self {
+        self.targets = targets;
+        self
+    }
+
+    pub fn with_increment(mut self, increment: f64) -> Self {
+        self.increment = increment;
+        self
+    }
+
+    pub fn with_bid_duration(mut self, bid_duration: i64) -> Self {
+        self.bid_duration = Some(bid_duration);
+        self
+    }
+
+    pub fn with_withdraw_excess(mut self, withdraw_excess: bool) -> Self {
+        self.withdraw_excess = withdraw_excess;
+        self
+    }
+
+    /// Mints the manager needs offers for: every target and every active bid
+    pub fn token_mints(&self) -> Vec<&str> {
+        let mut mints: Vec<&str> = self.targets.iter().map(|t| t.token_mint.as_str()).collect();
+        for bid in &self.bids {
+            if !mints.contains(&bid.token_mint.as_str()) {
+                mints.push(&bid.token_mint);
+            }
+        }
+        mints
+    }
+
+    /// Compute the actions bringing the active bids in line with the targets.
+    ///
+    /// `offers` are the offers received by each token, `escrow_balance` is the escrow of the buyer in SOL
+    /// and `now` the current timestamp in seconds.
+    pub fn reconcile(
+        &self,
+        offers: &HashMap<String, Vec<Offer>>,
+        escrow_balance: f64,
+        now: i64,
+    ) -> BidPlan {
+        let mut actions = vec![];
+        let is_expired = |bid: &ActiveBid| bid.expiry > 0 && bid.expiry <= now;
+
+        for bid in &self.bids {
+            if !self.targets.iter().any(|t| t.token_mint == bid.token_mint) {
+                actions.push(BidAction::Cancel {
+                    token_mint: bid.token_mint.clone(),
+                    price: bid.price,
+                    reason: CancelReason::Untargeted,
+                });
+            } else if is_expired(bid) {
+                actions.push(BidAction::Cancel {
+                    token_mint: bid.token_mint.clone(),
+                    price: bid.price,
+                    reason: CancelReason::Expired,
+                });
+            }
+        }
+
+        for target in &self.targets {
+            let active = self
+                .bids
+                .iter()
+                .find(|bid| bid.token_mint == target.token_mint && !is_expired(bid));
+            let best_offer = offers
+                .get(&target.token_mint)
+                .into_iter()
+                .flatten()
+                .filter(|offer| offer.buyer != self.buyer)
+                .map(|offer| offer.price)
+                .max_by(f64::total_cmp);
+
+            let price = match best_offer {
+                Some(best_offer) => round_to_lamports(best_offer + self.increment),
+                None => round_to_lamports(target.min_price),
+            }
+            .max(target.min_price);
+
+            if price > target.max_price {
+                if let Some(active) = active {
+                    actions.push(BidAction::Cancel {
+                        token_mint: active.token_mint.clone(),
+                        price: active.price,
+                        reason: CancelReason::AboveMaxPrice,
+                    });
+                }
+                continue;
+            }
+
+            match active {
+                None => actions.push(BidAction::Place {
+                    token_mint: target.token_mint.clone(),
+                    price,
+                }),
+                Some(active) if round_to_lamports(active.price) == price => {}
+                Some(active) => actions.push(BidAction::ChangePrice {
+                    token_mint: target.token_mint.clone(),
+                    old: active.price,
+                    new: price,
+                }),
+            }
+        }
+
+        let required = self
+            .bids_after(&actions)
+            .iter()
+            .map(|bid| bid.price)
+            .fold(0.0, f64::max);
+        let escrow = if required > escrow_balance {
+            Some(EscrowAction::Deposit {
+                amount: round_to_lamports(required - escrow_balance),
+            })
+        } else if self.withdraw_excess && escrow_balance > required {
+            Some(EscrowAction::Withdraw {
+                amount: round_to_lamports(escrow_balance - required),
+            })
+        } else {
+            None
+        };
+
+        BidPlan {
+            actions,
+            escrow,
+            required_escrow: required,
+            expiry: self.bid_duration.map(|duration| now + duration),
+        }
+    }
+
+    /// Record the actions of a plan once their instructions have been sent
+    pub fn apply(&mut self, plan: &BidPlan) {
+        let expiry = plan.expiry.unwrap_or(0);
+        self.bids = self.bids_after(&plan.actions);
+        for bid in self.bids.iter_mut() {
+            let touched = plan.actions.iter().any(|action| match action {
+                BidAction::Place { token_mint, .. } | BidAction::ChangePrice { token_mint, .. } => {
+                    *token_mint == bid.token_mint
+                }
+                BidAction::Cancel { .. } => false,
+            });
+            if touched {
+                bid.expiry = expiry;
+            }
+        }
+    }
+
+    fn bids_after(&self, actions: &[BidAction]) -> Vec<ActiveBid> {
+        let mut bids = self.bids.clone();
+        for action in actions {
+            match action {
+                BidAction::Cancel {
+                    token_mint, price, ..
+                } => bids.retain(|bid| !(bid.token_mint == *token_mint && bid.price == *price)),
+                BidAction::ChangePrice {
+                    token_mint, new, ..
+                } => {
+                    if let Some(bid) = bids.iter_mut().find(|bid| bid.token_mint == *token_mint) {
+                        bid.price = *new;
+                    }
+                }
+                BidAction::Place { token_mint, price } => bids.push(ActiveBid {
+                    token_mint: token_mint.clone(),
+                    price: *price,
+                    expiry: 0,
+                }),
+            }
+        }
+        bids
+    }
+}
+
+fn round_to_lamports(sol: f64) -> f64 {
+    (sol * LAMPORTS_PER_SOL).round() / LAMPORTS_PER_SOL
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BidPlan {
+    /// Bid actions, cancels first
+    pub actions: Vec<BidAction>,
+    /// Escrow top-up or withdrawal needed to back the bids
+    pub escrow: Option<EscrowAction>,
+    /// Escrow in SOL needed to back the bids after the plan, the highest bid
+    pub required_escrow: f64,
+    /// Timestamp in seconds the placed and repriced bids expire at, computed once when planning
+    /// so the instructions and [`BidManager::apply`] agree. `None` uses the API default
+    pub expiry: Option<i64>,
+}
+
+impl BidPlan {
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty() && self.escrow.is_none()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BidAction {
+    /// Place a new bid, via `Instructions::buy`
+    Place { token_mint: String, price: f64 },
+    /// Change the price of a bid, via `Instructions::buy_change_price`
+    ChangePrice {
+        token_mint: String,
+        old: f64,
+        new: f64,
+    },
+    /// Cancel a bid, via `Instructions::buy_cancel`
+    Cancel {
+        token_mint: String,
+        price: f64,
+        reason: CancelReason,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CancelReason {
+    /// The token is no longer a target
+    Untargeted,
+    /// The bid expired
+    Expired,
+    /// Outbidding the best offer would exceed the max price of the target
+    AboveMaxPrice,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EscrowAction {
+    /// Deposit SOL to escrow, via `Instructions::deposit`
+    Deposit { amount: f64 },
+    /// Withdraw SOL from escrow, via `Instructions::withdraw`
+    Withdraw { amount: f64 },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BidStep {
+    Bid(BidAction),
+    Escrow(EscrowAction),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BidInstruction {
+    pub step: BidStep,
+    pub instruction: InstructionResponse,
+}
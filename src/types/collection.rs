@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::error::MagicedenError;
 
-use super::{Attribute, Collection, Listing};
+use super::{Attribute, AttributeFilter, Collection, Listing, LAMPORTS_PER_SOL};
 
 #[derive(Clone, Serialize, Default, Debug, Builder, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
@@ -49,6 +49,7 @@ pub struct CollectionActivitiy {
 #[serde(rename_all = "camelCase")]
 pub struct CollectionStatsResponse {
     pub symbol: String,
+    /// Floor price in lamports
     #[serde(skip_serializing_if = "Option::is_none")]
     pub floor_price: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -57,6 +58,127 @@ pub struct CollectionStatsResponse {
     pub avg_price24hr: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub volume_all: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub volume24hr: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub txns24hr: Option<i64>,
+    /// Sum of all listing prices
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub listed_total_value: Option<f64>,
+    /// Floor price 24 hours ago
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub floor_price24hr: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_supply: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unique_holders: Option<i64>,
+}
+
+impl CollectionStatsResponse {
+    /// Floor price in SOL
+    pub fn floor_price_sol(&self) -> Option<f64> {
+        self.floor_price.map(|floor| floor / LAMPORTS_PER_SOL)
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectionAttributesResponse {
+    pub results: CollectionAttributes,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectionAttributes {
+    pub symbol: String,
+    pub available_attributes: Vec<AvailableAttribute>,
+}
+
+impl CollectionAttributes {
+    /// Distinct trait types of the collection, in the order returned by the API
+    pub fn trait_types(&self) -> Vec<&str> {
+        let mut trait_types: Vec<&str> = vec![];
+        for available in &self.available_attributes {
+            if !trait_types.contains(&available.attribute.trait_type.as_str()) {
+                trait_types.push(&available.attribute.trait_type);
+            }
+        }
+        trait_types
+    }
+
+    /// Values and counts of a trait type
+    pub fn values(&self, trait_type: &str) -> Vec<&AvailableAttribute> {
+        self.available_attributes
+            .iter()
+            .filter(|available| available.attribute.trait_type == trait_type)
+            .collect()
+    }
+
+    /// Floor price in SOL of listings with the given trait
+    pub fn trait_floor(&self, trait_type: &str, value: &str) -> Option<f64> {
+        self.available_attributes
+            .iter()
+            .find(|available| {
+                available.attribute.trait_type == trait_type && available.attribute.value == value
+            })
+            .and_then(|available| available.floor_sol())
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AvailableAttribute {
+    /// Number of listed tokens with this trait
+    pub count: i64,
+    /// Floor price in lamports of listed tokens with this trait
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub floor: Option<f64>,
+    pub attribute: Attribute,
+}
+
+impl AvailableAttribute {
+    /// Floor price in SOL of listed tokens with this trait
+    pub fn floor_sol(&self) -> Option<f64> {
+        self.floor.map(|floor| floor / LAMPORTS_PER_SOL)
+    }
+}
+
+#[derive(Clone, Serialize, Default, Debug, Builder, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[builder(name = "CollectionTimeSeriesRequestArgs")]
+#[builder(pattern = "mutable")]
+#[builder(setter(into, strip_option), default)]
+#[builder(derive(Debug))]
+#[builder(build_fn(error = "MagicedenError"))]
+pub struct CollectionTimeSeriesRequest {
+    /// The resolution of the data points, default '1d'. [1h, 1d, 7d]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolution: Option<String>,
+    /// Start of the time range in unix seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<i64>,
+    /// End of the time range in unix seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<i64>,
+}
+
+pub type CollectionTimeSeriesResponse = Vec<CollectionStatsPoint>;
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectionStatsPoint {
+    /// Start of the interval in unix seconds
+    pub timestamp: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub floor_price: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub listed_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub volume: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub txns: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avg_price: Option<f64>,
 }
 
 #[derive(Clone, Serialize, Default, Debug, Builder, Deserialize, PartialEq)]
@@ -92,7 +214,7 @@ pub struct CollectionListingsRequest {
     /// Filter listings that are more than this price
     pub max_price: Option<f64>,
     /// Represents a filtering mechanism where the elements within each inner array are logically ANDed, and the resulting arrays are ORed together at the top level. Each inner array consists of objects with two properties: traitType (a string) and value (a string).
-    pub attributes: Option<Vec<Attribute>>,
+    pub attributes: Option<AttributeFilter>,
     /// The field to sort the listings, default 'listPrice'. [listPrice, updatedAt]
     pub sort: Option<String>,
     /// The direction returned elements should be sorted in, default 'asc'. [asc, desc]
@@ -107,5 +229,133 @@ pub struct HolderStatsResponse {
     pub symbol: String,
     pub total_supply: Option<i32>,
     pub unique_holders: Option<i32>,
-    // todo: add holder stats
+    /// Distribution of holders by number of tokens held
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_histogram: Option<TokenHistogram>,
+    /// Largest holders, sorted by number of tokens held descending
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_holders: Option<Vec<TopHolder>>,
+}
+
+impl HolderStatsResponse {
+    /// Share of the supply held by the `n` largest holders, between 0 and 1
+    pub fn top_n_share(&self, n: usize) -> Option<f64> {
+        let total_supply = self.total_supply.filter(|supply| *supply > 0)?;
+        let top_holders = self.top_holders.as_ref()?;
+
+        let mut tokens: Vec<i64> = top_holders.iter().map(|holder| holder.tokens).collect();
+        tokens.sort_unstable_by(|a, b| b.cmp(a));
+        let held: i64 = tokens.iter().take(n).sum();
+
+        Some(held as f64 / total_supply as f64)
+    }
+
+    /// Share of the supply held by the 10 largest holders, between 0 and 1
+    pub fn top10_share(&self) -> Option<f64> {
+        self.top_n_share(10)
+    }
+
+    /// Gini coefficient of the holder distribution computed from the token histogram,
+    /// 0 means every holder owns the same amount and values close to 1 mean a few holders own most of the supply
+    pub fn gini(&self) -> Option<f64> {
+        let bars = &self.token_histogram.as_ref()?.bars;
+
+        let holders: f64 = bars.iter().map(|bar| bar.holders as f64).sum();
+        let tokens: f64 = bars
+            .iter()
+            .map(|bar| bar.holders as f64 * bar.tokens as f64)
+            .sum();
+        if holders == 0.0 || tokens == 0.0 {
+            return None;
+        }
+
+        let mut abs_diff = 0.0;
+        for a in bars {
+            for b in bars {
+                abs_diff +=
+                    a.holders as f64 * b.holders as f64 * (a.tokens as f64 - b.tokens as f64).abs();
+            }
+        }
+        let mean = tokens / holders;
+
+        Some(abs_diff / (2.0 * holders * holders * mean))
+    }
+
+    /// Breakdown of holders into the tiers of [`OWNER_TIERS`]
+    pub fn owner_tiers(&self) -> Option<Vec<OwnerTier>> {
+        let bars = &self.token_histogram.as_ref()?.bars;
+
+        let tiers = OWNER_TIERS
+            .iter()
+            .map(|(min_tokens, max_tokens)| {
+                let in_tier = bars.iter().filter(|bar| {
+                    bar.tokens >= *min_tokens && max_tokens.is_none_or(|max| bar.tokens <= max)
+                });
+                let (holders, tokens) = in_tier.fold((0, 0), |(holders, tokens), bar| {
+                    (holders + bar.holders, tokens + bar.holders * bar.tokens)
+                });
+
+                OwnerTier {
+                    min_tokens: *min_tokens,
+                    max_tokens: *max_tokens,
+                    holders,
+                    tokens,
+                }
+            })
+            .collect();
+
+        Some(tiers)
+    }
+}
+
+/// Inclusive token count ranges used by [`HolderStatsResponse::owner_tiers`]
+pub const OWNER_TIERS: [(i64, Option<i64>); 5] = [
+    (1, Some(1)),
+    (2, Some(5)),
+    (6, Some(10)),
+    (11, Some(50)),
+    (51, None),
+];
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenHistogram {
+    pub bars: Vec<HistogramBar>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+pub struct HistogramBar {
+    /// Number of tokens held
+    #[serde(rename = "l_val")]
+    pub tokens: i64,
+    /// Number of holders holding this many tokens
+    #[serde(rename = "hight")]
+    pub holders: i64,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TopHolder {
+    pub owner: String,
+    pub tokens: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner_display: Option<OwnerDisplay>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OwnerDisplay {
+    /// Solana name service domain of the owner
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sol: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OwnerTier {
+    pub min_tokens: i64,
+    /// `None` means the tier has no upper bound
+    pub max_tokens: Option<i64>,
+    pub holders: i64,
+    pub tokens: i64,
 }
@@ -19,9 +19,75 @@ pub struct InstructionResponse {
 #[serde(rename_all = "camelCase")]
 pub struct Attribute {
     pub value: String,
+    #[serde(alias = "trait_type")]
     pub trait_type: String,
 }
 
+impl Attribute {
+    pub fn new<T: Into<String>, V: Into<String>>(trait_type: T, value: V) -> Self {
+        Self {
+            value: value.into(),
+            trait_type: trait_type.into(),
+        }
+    }
+}
+
+/// Attribute filter of listing queries: attributes within a group are ANDed and groups are ORed.
+///
+/// Serialized as the JSON encoded `[[{"value":..,"traitType":..}, ..], ..]` the API expects.
+///
+/// ```
+/// use magiceden_rs::types::{Attribute, AttributeFilter};
+///
+/// // (Fur = Gold AND Hat = Crown) OR Eyes = Laser
+/// let filter = AttributeFilter::any_of([
+///     AttributeFilter::all_of([Attribute::new("Fur", "Gold"), Attribute::new("Hat", "Crown")]),
+///     AttributeFilter::all_of([Attribute::new("Eyes", "Laser")]),
+/// ]);
+/// assert_eq!(filter.groups().len(), 2);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AttributeFilter(Vec<Vec<Attribute>>);
+
+impl AttributeFilter {
+    /// Filter matching tokens that have every one of `attributes`
+    pub fn all_of<I: IntoIterator<Item = Attribute>>(attributes: I) -> Self {
+        Self(vec![attributes.into_iter().collect()])
+    }
+
+    /// Filter matching tokens that match any of `filters`
+    pub fn any_of<I: IntoIterator<Item = AttributeFilter>>(filters: I) -> Self {
+        Self(filters.into_iter().flat_map(|filter| filter.0).collect())
+    }
+
+    /// Add an alternative to the filter
+    pub fn or(mut self, filter: AttributeFilter) -> Self {
+        self.0.extend(filter.0);
+        self
+    }
+
+    /// ORed groups of ANDed attributes
+    pub fn groups(&self) -> &[Vec<Attribute>] {
+        &self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().all(|group| group.is_empty())
+    }
+}
+
+impl From<Attribute> for AttributeFilter {
+    fn from(attribute: Attribute) -> Self {
+        Self::all_of([attribute])
+    }
+}
+
+impl From<Vec<Vec<Attribute>>> for AttributeFilter {
+    fn from(groups: Vec<Vec<Attribute>>) -> Self {
+        Self(groups)
+    }
+}
+
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Collection {
@@ -70,6 +136,32 @@ pub struct Listing {
     pub expiry: i64,
 }
 
+impl Listing {
+    /// Rank of the token from `provider`, see [`Rarity::rank`]
+    pub fn rank(&self, provider: RarityProvider) -> Option<i32> {
+        self.rarity.rank(provider)
+    }
+
+    /// Rank of the token from the first available provider of [`RarityProvider::DEFAULT_ORDER`]
+    pub fn best_rank(&self) -> Option<i32> {
+        self.rarity.best_rank()
+    }
+
+    /// Listing price divided by the rarity score `1 + log10(supply / rank)` of the token, lower is
+    /// cheaper for its rarity.
+    ///
+    /// The least rare token keeps its price and every tenfold rarity (top 10%, top 1%, ...) divides
+    /// it by one more: out of 10000 tokens, rank 100 listed at 9 SOL is adjusted to 3 SOL and rank 1
+    /// listed at 25 SOL to 5 SOL. `None` when the token is unranked or `supply` is not positive.
+    pub fn rarity_adjusted_price(&self, supply: i64, order: &[RarityProvider]) -> Option<f64> {
+        let percentile = self.rarity.percentile_by(supply, order)?;
+        if percentile <= 0.0 {
+            return None;
+        }
+        Some(self.price / (1.0 - percentile.log10()))
+    }
+}
+
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
 pub struct Extra {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -87,6 +179,68 @@ pub struct Rarity {
     pub merarity: Option<Merarity>,
 }
 
+/// Rarity providers returned with listings
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RarityProvider {
+    Howrare,
+    Moonrank,
+    Merarity,
+}
+
+impl RarityProvider {
+    /// Preference order used by [`Rarity::best_rank`]
+    pub const DEFAULT_ORDER: [RarityProvider; 3] = [
+        RarityProvider::Moonrank,
+        RarityProvider::Howrare,
+        RarityProvider::Merarity,
+    ];
+}
+
+impl Rarity {
+    /// Rank of the token from `provider`. Moonrank ranks of an incomplete crawl are ignored.
+    pub fn rank(&self, provider: RarityProvider) -> Option<i32> {
+        match provider {
+            RarityProvider::Howrare => self.howrare.as_ref().map(|howrare| howrare.rank),
+            RarityProvider::Moonrank => self
+                .moonrank
+                .as_ref()
+                .filter(|moonrank| moonrank.crawl.complete)
+                .map(|moonrank| moonrank.rank),
+            RarityProvider::Merarity => self.merarity.as_ref().and_then(|merarity| merarity.rank),
+        }
+        .filter(|rank| *rank > 0)
+    }
+
+    /// Rank of the token from the first available provider of [`RarityProvider::DEFAULT_ORDER`]
+    pub fn best_rank(&self) -> Option<i32> {
+        self.best_rank_by(&RarityProvider::DEFAULT_ORDER)
+            .map(|(_, rank)| rank)
+    }
+
+    /// Rank of the token from the first available provider of `order`, along with that provider
+    pub fn best_rank_by(&self, order: &[RarityProvider]) -> Option<(RarityProvider, i32)> {
+        order
+            .iter()
+            .find_map(|provider| self.rank(*provider).map(|rank| (*provider, rank)))
+    }
+
+    /// Share of the collection supply ranked at or above the token, between 0 and 1.
+    /// A token ranked 1 out of 10000 has a percentile of 0.0001.
+    pub fn percentile(&self, supply: i64) -> Option<f64> {
+        self.percentile_by(supply, &RarityProvider::DEFAULT_ORDER)
+    }
+
+    /// Same as [`Rarity::percentile`] using the rank from the first available provider of `order`
+    pub fn percentile_by(&self, supply: i64, order: &[RarityProvider]) -> Option<f64> {
+        if supply <= 0 {
+            return None;
+        }
+        let (_, rank) = self.best_rank_by(order)?;
+        Some((rank as f64 / supply as f64).min(1.0))
+    }
+}
+
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
 pub struct Howrare {
     pub rank: i32,
@@ -131,6 +285,9 @@ pub struct AttributeWithCount {
     pub count: Option<i32>,
 }
 
+/// Number of lamports in one SOL
+pub const LAMPORTS_PER_SOL: f64 = 1_000_000_000.0;
+
 pub fn default_true() -> bool {
     true
 }
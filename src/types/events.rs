@@ -0,0 +1,162 @@
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use super::{CollectionListingsResponse, Listing};
+
+/// Change of a listing between two snapshots of a collection's listings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "type")]
+pub enum ListingEvent {
+    /// The token was listed
+    Listed { listing: Listing },
+    /// The listing was removed (sold or cancelled)
+    Delisted { listing: Listing },
+    /// The listing price changed, prices in SOL
+    PriceChanged {
+        listing: Listing,
+        old: f64,
+        new: f64,
+    },
+    /// The token was relisted by another seller
+    SellerChanged {
+        listing: Listing,
+        old: String,
+        new: String,
+    },
+    /// The listing expiry changed
+    ExpiryChanged {
+        listing: Listing,
+        old: i64,
+        new: i64,
+    },
+}
+
+impl ListingEvent {
+    /// Listing the event is about, the removed listing for `Delisted` and the current one otherwise
+    pub fn listing(&self) -> &Listing {
+        match self {
+            ListingEvent::Listed { listing }
+            | ListingEvent::Delisted { listing }
+            | ListingEvent::PriceChanged { listing, .. }
+            | ListingEvent::SellerChanged { listing, .. }
+            | ListingEvent::ExpiryChanged { listing, .. } => listing,
+        }
+    }
+}
+
+/// Field identifying a listing across snapshots
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ListingKey {
+    /// One listing per token, a relist by another seller is a `SellerChanged`
+    #[default]
+    TokenMint,
+    /// One listing per listing account, a relist by another seller is a `Delisted` and a `Listed`
+    PdaAddress,
+}
+
+impl ListingKey {
+    pub fn of<'a>(&self, listing: &'a Listing) -> &'a str {
+        match self {
+            ListingKey::TokenMint => &listing.token_mint,
+            ListingKey::PdaAddress => &listing.pda_address,
+        }
+    }
+}
+
+/// Events turning the `previous` snapshot of listings into the `current` one, keyed by token mint.
+///
+/// Events of current listings come first in the order of `current`, followed by the delisted ones.
+pub fn diff_listings(previous: &[Listing], current: &[Listing]) -> Vec<ListingEvent> {
+    let mut tracker = ListingTracker::new(ListingKey::TokenMint);
+    tracker.update(previous.to_vec());
+    tracker.update(current.to_vec())
+}
+
+/// Tracks the listings of a collection across snapshots, e.g. periodic `Collections::listings` calls
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListingTracker {
+    key: ListingKey,
+    listings: Vec<Listing>,
+}
+
+impl ListingTracker {
+    pub fn new(key: ListingKey) -> Self {
+        Self {
+            key,
+            listings: vec![],
+        }
+    }
+
+    /// Listings of the last snapshot
+    pub fn listings(&self) -> &[Listing] {
+        &self.listings
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Listing> {
+        self.listings
+            .iter()
+            .find(|listing| self.key.of(listing) == key)
+    }
+
+    /// Replace the tracked listings with a new snapshot and return the changes.
+    ///
+    /// Only the first listing of a key is kept when the snapshot repeats it.
+    pub fn update(&mut self, snapshot: CollectionListingsResponse) -> Vec<ListingEvent> {
+        let key = self.key;
+        let mut seen = HashSet::new();
+        let snapshot: Vec<Listing> = snapshot
+            .into_iter()
+            .filter(|listing| seen.insert(key.of(listing).to_string()))
+            .collect();
+        let previous: HashMap<&str, &Listing> = self
+            .listings
+            .iter()
+            .map(|listing| (key.of(listing), listing))
+            .collect();
+
+        let mut events = vec![];
+        for listing in &snapshot {
+            let Some(old) = previous.get(key.of(listing)) else {
+                events.push(ListingEvent::Listed {
+                    listing: listing.clone(),
+                });
+                continue;
+            };
+
+            if old.seller != listing.seller {
+                events.push(ListingEvent::SellerChanged {
+                    listing: listing.clone(),
+                    old: old.seller.clone(),
+                    new: listing.seller.clone(),
+                });
+            }
+            if old.price != listing.price {
+                events.push(ListingEvent::PriceChanged {
+                    listing: listing.clone(),
+                    old: old.price,
+                    new: listing.price,
+                });
+            }
+            if old.expiry != listing.expiry {
+                events.push(ListingEvent::ExpiryChanged {
+                    listing: listing.clone(),
+                    old: old.expiry,
+                    new: listing.expiry,
+                });
+            }
+        }
+        for listing in &self.listings {
+            if !seen.contains(key.of(listing)) {
+                events.push(ListingEvent::Delisted {
+                    listing: listing.clone(),
+                });
+            }
+        }
+
+        self.listings = snapshot;
+        events
+    }
+}
@@ -0,0 +1,489 @@
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::error::MagicedenError;
+
+/// EVM chains served by the v3 RTP API
+#[derive(Default, Debug, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EvmChain {
+    #[default]
+    Ethereum,
+    Polygon,
+    Base,
+    Arbitrum,
+    Bsc,
+}
+
+impl EvmChain {
+    /// Path segment of the chain in the v3 RTP API
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EvmChain::Ethereum => "ethereum",
+            EvmChain::Polygon => "polygon",
+            EvmChain::Base => "base",
+            EvmChain::Arbitrum => "arbitrum",
+            EvmChain::Bsc => "bsc",
+        }
+    }
+}
+
+impl std::fmt::Display for EvmChain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvmCurrency {
+    pub contract: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decimals: Option<u8>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvmAmount {
+    /// Amount in the smallest unit of the currency
+    pub raw: String,
+    pub decimal: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usd: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub native: Option<f64>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvmPrice {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<EvmCurrency>,
+    pub amount: EvmAmount,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub net_amount: Option<EvmAmount>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvmOrderSummary {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<EvmPrice>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maker: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub valid_from: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub valid_until: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+pub struct EvmVolume {
+    #[serde(rename = "1day", skip_serializing_if = "Option::is_none")]
+    pub one_day: Option<f64>,
+    #[serde(rename = "7day", skip_serializing_if = "Option::is_none")]
+    pub seven_days: Option<f64>,
+    #[serde(rename = "30day", skip_serializing_if = "Option::is_none")]
+    pub thirty_days: Option<f64>,
+    #[serde(rename = "allTime", skip_serializing_if = "Option::is_none")]
+    pub all_time: Option<f64>,
+}
+
+#[derive(Clone, Serialize, Default, Debug, Builder, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[builder(name = "EvmCollectionsRequestArgs")]
+#[builder(pattern = "mutable")]
+#[builder(setter(into, strip_option), default)]
+#[builder(derive(Debug))]
+#[builder(build_fn(error = "MagicedenError"))]
+pub struct EvmCollectionsRequest {
+    /// Filter to a particular collection id, e.g. a contract address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Filter to a particular collection slug
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    /// Filter to a particular contract address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contract: Option<String>,
+    /// Sort by field. [allTimeVolume, 1DayVolume, 7DayVolume, 30DayVolume, createdAt, floorAskPrice]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_by: Option<String>,
+    /// The numbers of items to return, default 20, min 1, max 20
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    /// Continuation token returned by the previous page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub continuation: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvmCollectionsResponse {
+    pub collections: Vec<EvmCollection>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub continuation: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvmCollection {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub primary_contract: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_count: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_sale_count: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub floor_ask: Option<EvmOrderSummary>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_bid: Option<EvmOrderSummary>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub volume: Option<EvmVolume>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+}
+
+#[derive(Clone, Serialize, Default, Debug, Builder, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[builder(name = "EvmTokensRequestArgs")]
+#[builder(pattern = "mutable")]
+#[builder(setter(into, strip_option), default)]
+#[builder(derive(Debug))]
+#[builder(build_fn(error = "MagicedenError"))]
+pub struct EvmTokensRequest {
+    /// Filter to a particular collection id
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection: Option<String>,
+    /// Filter to a particular token, `<contract>:<tokenId>`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tokens: Option<String>,
+    /// Sort by field. [floorAskPrice, tokenId, rarity, updatedAt, listedAt]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_by: Option<String>,
+    /// The direction returned elements should be sorted in. [asc, desc]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_direction: Option<String>,
+    /// The numbers of items to return, default 20, min 1, max 100
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    /// Continuation token returned by the previous page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub continuation: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvmTokensResponse {
+    pub tokens: Vec<EvmTokenWithMarket>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub continuation: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvmTokenWithMarket {
+    pub token: EvmToken,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub market: Option<EvmTokenMarket>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvmToken {
+    pub contract: String,
+    pub token_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    /// Token standard. [erc721, erc1155]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection: Option<EvmTokenCollection>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rarity: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rarity_rank: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvmTokenCollection {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvmTokenMarket {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub floor_ask: Option<EvmOrderSummary>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_bid: Option<EvmOrderSummary>,
+}
+
+#[derive(Clone, Serialize, Default, Debug, Builder, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[builder(name = "EvmOrdersRequestArgs")]
+#[builder(pattern = "mutable")]
+#[builder(setter(into, strip_option), default)]
+#[builder(derive(Debug))]
+#[builder(build_fn(error = "MagicedenError"))]
+pub struct EvmOrdersRequest {
+    /// Filter to orders on a particular contract
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contracts: Option<String>,
+    /// Filter to orders on a particular token, `<contract>:<tokenId>`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    /// Filter to orders made by a particular wallet
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maker: Option<String>,
+    /// Order status. [active, inactive, expired, cancelled, filled]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    /// Sort by field. [createdAt, price, updatedAt]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_by: Option<String>,
+    /// The numbers of items to return, default 50, min 1, max 1000
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    /// Continuation token returned by the previous page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub continuation: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvmOrdersResponse {
+    pub orders: Vec<EvmOrder>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub continuation: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvmOrder {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /// Order side. [buy, sell]
+    pub side: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_set_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contract: Option<String>,
+    pub maker: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub taker: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<EvmPrice>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub valid_from: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub valid_until: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity_filled: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity_remaining: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub criteria: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+#[derive(Clone, Serialize, Default, Debug, Builder, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[builder(name = "EvmUserActivityRequestArgs")]
+#[builder(pattern = "mutable")]
+#[builder(setter(into, strip_option), default)]
+#[builder(derive(Debug))]
+#[builder(build_fn(error = "MagicedenError"))]
+pub struct EvmUserActivityRequest {
+    /// Wallet address to get activity for
+    pub users: String,
+    /// Filter to a particular collection id
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection: Option<String>,
+    /// Activity type. [sale, ask, transfer, mint, bid, bid_cancel, ask_cancel]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub types: Option<String>,
+    /// The numbers of items to return, default 20, min 1, max 20
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    /// Continuation token returned by the previous page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub continuation: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvmUserActivityResponse {
+    pub activities: Vec<EvmActivity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub continuation: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvmActivity {
+    pub r#type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<EvmPrice>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<i64>,
+    pub timestamp: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contract: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<EvmActivityToken>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection: Option<EvmActivityCollection>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tx_hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_index: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub batch_index: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvmActivityToken {
+    pub token_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_image: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvmActivityCollection {
+    pub collection_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection_image: Option<String>,
+}
+
+#[derive(Clone, Serialize, Default, Debug, Builder, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[builder(name = "EvmSalesRequestArgs")]
+#[builder(pattern = "mutable")]
+#[builder(setter(into, strip_option), default)]
+#[builder(derive(Debug))]
+#[builder(build_fn(error = "MagicedenError"))]
+pub struct EvmSalesRequest {
+    /// Filter to a particular collection id
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection: Option<String>,
+    /// Filter to a particular contract
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contract: Option<String>,
+    /// Filter to a particular token, `<contract>:<tokenId>`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tokens: Option<String>,
+    /// Get events after a particular unix timestamp (inclusive)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_timestamp: Option<i64>,
+    /// Get events before a particular unix timestamp (inclusive)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_timestamp: Option<i64>,
+    /// The numbers of items to return, default 100, min 1, max 1000
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    /// Continuation token returned by the previous page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub continuation: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvmSalesResponse {
+    pub sales: Vec<EvmSale>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub continuation: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvmSale {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sale_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<EvmToken>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_side: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_kind: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<EvmPrice>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tx_hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_index: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub batch_index: Option<i64>,
+    pub timestamp: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wash_trading_score: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub royalty_fee_bps: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub marketplace_fee_bps: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paid_full_royalty: Option<bool>,
+}
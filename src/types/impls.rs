@@ -1 +1,27 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
+use super::{Attribute, AttributeFilter};
+
+impl Serialize for AttributeFilter {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let json = serde_json::to_string(self.groups()).map_err(serde::ser::Error::custom)?;
+        serializer.serialize_str(&json)
+    }
+}
+
+impl<'de> Deserialize<'de> for AttributeFilter {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Json(String),
+            Groups(Vec<Vec<Attribute>>),
+        }
+
+        let groups = match Repr::deserialize(deserializer)? {
+            Repr::Json(json) => serde_json::from_str(&json).map_err(serde::de::Error::custom)?,
+            Repr::Groups(groups) => groups,
+        };
+        Ok(AttributeFilter::from(groups))
+    }
+}
@@ -0,0 +1,124 @@
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::error::MagicedenError;
+
+#[derive(Clone, Serialize, Default, Debug, Builder, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[builder(name = "LaunchpadCollectionsRequestArgs")]
+#[builder(pattern = "mutable")]
+#[builder(setter(into, strip_option), default)]
+#[builder(derive(Debug))]
+#[builder(build_fn(error = "MagicedenError"))]
+pub struct LaunchpadCollectionsRequest {
+    /// The number of items to skip, default 0, min 0
+    pub offset: Option<i64>,
+    /// The numbers of items to return, default 200, min 1, max 500
+    pub limit: Option<i64>,
+}
+
+pub type LaunchpadCollectionsResponse = Vec<LaunchpadCollection>;
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LaunchpadCollection {
+    pub symbol: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub featured: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub edition: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    /// Public mint price in SOL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<f64>,
+    /// Total number of items that can be minted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<i64>,
+    /// Launch date as an ISO-8601 string
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub launch_datetime: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mint: Option<LaunchpadMint>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<LaunchpadState>,
+}
+
+impl LaunchpadCollection {
+    /// Mint stages of the collection, empty if the API returned no schedule
+    pub fn phases(&self) -> &[LaunchpadPhase] {
+        self.state
+            .as_ref()
+            .map(|state| state.phases.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Phase that is live at `timestamp` (unix seconds), if any
+    pub fn phase_at(&self, timestamp: i64) -> Option<&LaunchpadPhase> {
+        self.phases()
+            .iter()
+            .find(|phase| phase.is_live_at(timestamp))
+    }
+
+    /// Next phase starting after `timestamp` (unix seconds), if any
+    pub fn next_phase(&self, timestamp: i64) -> Option<&LaunchpadPhase> {
+        self.phases()
+            .iter()
+            .filter(|phase| phase.start_time > timestamp)
+            .min_by_key(|phase| phase.start_time)
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LaunchpadMint {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub candy_machine_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub config: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub treasury: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LaunchpadState {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items_available: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items_redeemed: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items_remaining: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_sold_out: Option<bool>,
+    #[serde(default)]
+    pub phases: Vec<LaunchpadPhase>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LaunchpadPhase {
+    pub label: String,
+    /// Phase start as unix seconds
+    pub start_time: i64,
+    /// Phase end as unix seconds, `None` if the phase runs until sold out
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<i64>,
+    /// Mint price of the phase in SOL
+    pub price: f64,
+    /// Maximum mints per wallet, `None` means unlimited
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_per_wallet: Option<i64>,
+    /// Whether minting in this phase requires an allowlist
+    #[serde(default)]
+    pub is_allowlist: bool,
+}
+
+impl LaunchpadPhase {
+    pub fn is_live_at(&self, timestamp: i64) -> bool {
+        self.start_time <= timestamp && self.end_time.is_none_or(|end| timestamp < end)
+    }
+}
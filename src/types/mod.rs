@@ -1,16 +1,32 @@
+mod bids;
 mod collection;
 mod common;
+mod events;
+mod evm;
 mod instructions;
+mod launchpad;
 mod marketplace;
 mod mmm;
+mod options;
+mod ordinals;
+mod reprice;
+mod sweep;
 mod token;
 mod wallet;
 
+pub use bids::*;
 pub use collection::*;
 pub use common::*;
+pub use events::*;
+pub use evm::*;
 pub use instructions::*;
+pub use launchpad::*;
 pub use marketplace::*;
 pub use mmm::*;
+pub use options::*;
+pub use ordinals::*;
+pub use reprice::*;
+pub use sweep::*;
 pub use token::*;
 pub use wallet::*;
 
@@ -0,0 +1,81 @@
+use std::time::Duration;
+
+use derive_builder::Builder;
+
+use crate::error::MagicedenError;
+
+/// Per-request overrides accepted by the `*_with_options` variants of the API methods
+#[derive(Clone, Default, Debug, Builder, PartialEq)]
+#[builder(name = "RequestOptionsArgs")]
+#[builder(pattern = "mutable")]
+#[builder(setter(into, strip_option), default)]
+#[builder(derive(Debug))]
+#[builder(build_fn(error = "MagicedenError"))]
+pub struct RequestOptions {
+    /// Timeout of every attempt, from sending the request until the response body is read
+    pub timeout: Option<Duration>,
+    /// Maximum number of retries of a rate limited request, `0` disables retries.
+    /// The client's backoff still bounds the total time spent retrying.
+    pub max_retries: Option<u32>,
+    /// Extra headers sent with the request
+    pub headers: Vec<(String, String)>,
+    /// Extra query parameters appended to the request
+    pub query: Vec<(String, String)>,
+    /// Sent as the `Idempotency-Key` header so a retried request is not executed twice
+    pub idempotency_key: Option<String>,
+    /// Ask the API and any cache in between for a fresh response with `Cache-Control: no-cache`
+    pub bypass_cache: bool,
+}
+
+impl RequestOptionsArgs {
+    /// Add an extra header
+    pub fn header<N: Into<String>, V: Into<String>>(&mut self, name: N, value: V) -> &mut Self {
+        self.headers
+            .get_or_insert_with(Vec::new)
+            .push((name.into(), value.into()));
+        self
+    }
+
+    /// Add an extra query parameter
+    pub fn query_param<N: Into<String>, V: Into<String>>(
+        &mut self,
+        name: N,
+        value: V,
+    ) -> &mut Self {
+        self.query
+            .get_or_insert_with(Vec::new)
+            .push((name.into(), value.into()));
+        self
+    }
+}
+
+impl RequestOptions {
+    /// Options disabling retries, for latency critical calls better failed fast than late
+    pub fn no_retry() -> Self {
+        Self {
+            max_retries: Some(0),
+            ..Default::default()
+        }
+    }
+
+    /// Apply the timeout, headers and query parameters to `request`.
+    /// Invalid header names or values surface as an error when the request is built.
+    pub(crate) fn apply(&self, mut request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        if let Some(timeout) = self.timeout {
+            request = request.timeout(timeout);
+        }
+        if !self.query.is_empty() {
+            request = request.query(&self.query);
+        }
+        for (name, value) in &self.headers {
+            request = request.header(name, value);
+        }
+        if let Some(key) = &self.idempotency_key {
+            request = request.header("Idempotency-Key", key);
+        }
+        if self.bypass_cache {
+            request = request.header(reqwest::header::CACHE_CONTROL, "no-cache");
+        }
+        request
+    }
+}
@@ -0,0 +1,393 @@
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::error::MagicedenError;
+
+#[derive(Clone, Serialize, Default, Debug, Builder, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[builder(name = "OrdinalsTokensRequestArgs")]
+#[builder(pattern = "mutable")]
+#[builder(setter(into, strip_option), default)]
+#[builder(derive(Debug))]
+#[builder(build_fn(error = "MagicedenError"))]
+pub struct OrdinalsTokensRequest {
+    /// Comma-separated list of inscription ids to fetch
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_ids: Option<String>,
+    /// Collection symbol to filter tokens by
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection_symbol: Option<String>,
+    /// Owner address to filter tokens by
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner_address: Option<String>,
+    /// Show all items including unlisted ones, default false
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_all: Option<bool>,
+    /// The numbers of items to return, default 20, min 1, max 100
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    /// The number of items to skip, default 0, min 0, max 15000
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i64>,
+    /// Sort by field. [priceAsc, priceDesc, listedAtAsc, listedAtDesc, inscriptionNumberAsc, inscriptionNumberDesc]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_by: Option<String>,
+    /// Filter tokens listed at or above this price, in satoshis
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_price: Option<i64>,
+    /// Filter tokens listed at or below this price, in satoshis
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_price: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrdinalsTokensResponse {
+    pub tokens: Vec<Inscription>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Inscription {
+    /// Inscription id, `<txid>i<index>`
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inscription_number: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chain: Option<String>,
+    #[serde(rename = "contentURI", skip_serializing_if = "Option::is_none")]
+    pub content_uri: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+    #[serde(rename = "contentPreviewURI", skip_serializing_if = "Option::is_none")]
+    pub content_preview_uri: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sat: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sat_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sat_rarity: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub genesis_transaction: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub genesis_transaction_block_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub genesis_transaction_block_height: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meta: Option<InscriptionMeta>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection_symbol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection: Option<OrdinalsCollection>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location_block_height: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<String>,
+    /// Value of the output holding the inscription, in satoshis
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_value: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub listed: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub listed_at: Option<String>,
+    /// Listed price in satoshis
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub listed_price: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub listed_maker_fee_bp: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub listed_seller_receive_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item_type: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InscriptionMeta {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<Vec<InscriptionAttribute>>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+pub struct InscriptionAttribute {
+    pub trait_type: String,
+    pub value: serde_json::Value,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrdinalsCollection {
+    pub symbol: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_uri: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chain: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inscription_icon: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supply: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub twitter_link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discord_link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub website_link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_inscription_number: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_inscription_number: Option<i64>,
+}
+
+#[derive(Clone, Serialize, Default, Debug, Builder, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[builder(name = "OrdinalsActivitiesRequestArgs")]
+#[builder(pattern = "mutable")]
+#[builder(setter(into, strip_option), default)]
+#[builder(derive(Debug))]
+#[builder(build_fn(error = "MagicedenError"))]
+pub struct OrdinalsActivitiesRequest {
+    /// Collection symbol to filter activities by
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection_symbol: Option<String>,
+    /// Inscription id to filter activities by
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_id: Option<String>,
+    /// Owner address to filter activities by
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner_address: Option<String>,
+    /// Activity kind. [create, list, delist, buying_broadcasted, offer_placed, offer_cancelled, offer_accepted_broadcasted, transfer, ...]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /// The numbers of items to return, default 20, min 1, max 20
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    /// The number of items to skip, default 0, min 0, max 15000
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrdinalsActivitiesResponse {
+    pub activities: Vec<OrdinalsActivity>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrdinalsActivity {
+    pub kind: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chain: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection_symbol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection: Option<OrdinalsCollection>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<Inscription>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_inscription_number: Option<i64>,
+    pub created_at: String,
+    /// Listed or sale price in satoshis
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub listed_price: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old_owner: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_owner: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tx_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tx_block_height: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tx_block_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub btc_usd_price: Option<f64>,
+}
+
+#[derive(Clone, Serialize, Default, Debug, Builder, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[builder(name = "OrdinalsCollectionStatsRequestArgs")]
+#[builder(pattern = "mutable")]
+#[builder(setter(into, strip_option), default)]
+#[builder(derive(Debug))]
+#[builder(build_fn(error = "MagicedenError"))]
+pub struct OrdinalsCollectionStatsRequest {
+    /// Collection symbol to get stats for
+    pub collection_symbol: String,
+}
+
+/// Numeric stats are returned as strings by the Ordinals API, amounts are in satoshis
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrdinalsCollectionStatsResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_volume: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owners: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supply: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub floor_price: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_listed: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_transactions: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inscription_number_min: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inscription_number_max: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+}
+
+#[derive(Clone, Serialize, Default, Debug, Builder, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[builder(name = "OrdinalsPopularCollectionsRequestArgs")]
+#[builder(pattern = "mutable")]
+#[builder(setter(into, strip_option), default)]
+#[builder(derive(Debug))]
+#[builder(build_fn(error = "MagicedenError"))]
+pub struct OrdinalsPopularCollectionsRequest {
+    /// Time window. [1h, 6h, 1d, 7d, 30d]. Default 1d.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub window: Option<String>,
+    /// The numbers of items to return, default 12, max 100
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+}
+
+pub type OrdinalsPopularCollectionsResponse = Vec<OrdinalsCollection>;
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuneMarketInfoResponse {
+    pub rune: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rune_number: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ticker: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_supply: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub formatted_total_supply: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub divisibility: Option<i64>,
+    #[serde(rename = "imageURI", skip_serializing_if = "Option::is_none")]
+    pub image_uri: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_listed_unit_price: Option<RunePrice>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub floor_unit_price: Option<RunePrice>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub market_cap: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub holder_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub volume: Option<RuneVolume>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunePrice {
+    pub formatted: String,
+    pub value: String,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+pub struct RuneVolume {
+    #[serde(rename = "1d", skip_serializing_if = "Option::is_none")]
+    pub one_day: Option<f64>,
+    #[serde(rename = "7d", skip_serializing_if = "Option::is_none")]
+    pub seven_days: Option<f64>,
+    #[serde(rename = "30d", skip_serializing_if = "Option::is_none")]
+    pub thirty_days: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub all: Option<f64>,
+}
+
+#[derive(Clone, Serialize, Default, Debug, Builder, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[builder(name = "RuneOrdersRequestArgs")]
+#[builder(pattern = "mutable")]
+#[builder(setter(into, strip_option), default)]
+#[builder(derive(Debug))]
+#[builder(build_fn(error = "MagicedenError"))]
+pub struct RuneOrdersRequest {
+    /// Side of the order book. [sell]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub side: Option<String>,
+    /// Sort orders by. [unitPriceAsc, unitPriceDesc]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<String>,
+    /// The numbers of items to return, min 1, max 100
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    /// The number of items to skip, default 0, min 0
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuneOrdersResponse {
+    pub orders: Vec<RuneOrder>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuneOrder {
+    pub id: String,
+    pub rune: String,
+    pub side: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maker: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maker_receive_address: Option<String>,
+    /// Price per rune unit in satoshis
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub formatted_unit_price: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub formatted_amount: Option<String>,
+    /// Total price in satoshis
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuneBalanceResponse {
+    pub rune: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ticker: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+    pub total_balance: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub formatted_total_balance: Option<String>,
+}
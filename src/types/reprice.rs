@@ -0,0 +1,226 @@
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::error::MagicedenError;
+
+use super::{Attribute, CollectionAttributes, InstructionResponse, Listing, LAMPORTS_PER_SOL};
+
+/// Rule used to compute the target listing price of every item of an inventory
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PricingRule {
+    /// List every item at a fixed price in SOL
+    Fixed { price: f64 },
+    /// List every item `percent` below the collection floor
+    BelowFloor { percent: f64 },
+    /// List every item `percent` below the highest floor among its traits
+    BelowTraitFloor { percent: f64 },
+    /// Cancel every listing
+    Delist,
+}
+
+/// Token of the seller wallet managed by the repricer
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InventoryItem {
+    /// Token mint address.
+    pub token_mint: String,
+    /// Token account
+    pub token_account: String,
+    /// Attributes of the token, required by `PricingRule::BelowTraitFloor`
+    #[serde(default)]
+    pub attributes: Vec<Attribute>,
+}
+
+impl InventoryItem {
+    pub fn new<M: Into<String>, A: Into<String>>(token_mint: M, token_account: A) -> Self {
+        Self {
+            token_mint: token_mint.into(),
+            token_account: token_account.into(),
+            attributes: vec![],
+        }
+    }
+
+    pub fn with_attributes(mut self, attributes: Vec<Attribute>) -> Self {
+        self.attributes = attributes;
+        self
+    }
+}
+
+#[derive(Clone, Serialize, Default, Debug, Builder, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[builder(name = "RepriceRequestArgs")]
+#[builder(pattern = "mutable")]
+#[builder(setter(into, strip_option), default)]
+#[builder(derive(Debug))]
+#[builder(build_fn(error = "MagicedenError"))]
+pub struct RepriceRequest {
+    /// Seller wallet.
+    pub seller: String,
+    /// Auction house used for new listings.
+    pub auction_house_address: String,
+    /// Collection of the inventory, used to look up floor prices
+    pub collection_symbol: String,
+    /// Tokens to bring in line with the rule
+    pub items: Vec<InventoryItem>,
+    /// Pricing rule, required
+    pub rule: Option<PricingRule>,
+    /// Never list below this price in SOL
+    pub min_price: Option<f64>,
+    /// Seller referral wallet. Option
+    pub seller_referral: Option<String>,
+    /// timestamp in seconds in the future, 0 will default to 7 days. Option
+    pub expiry: Option<i64>,
+}
+
+/// Market data needed to evaluate a `PricingRule`, prices in SOL
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MarketPrices {
+    pub floor_price: Option<f64>,
+    pub attributes: Option<CollectionAttributes>,
+}
+
+impl RepriceRequest {
+    /// Target price in SOL of `item` according to the rule, `Err` holds the reason it can't be priced.
+    /// `Ok(None)` means the item should not be listed.
+    pub fn target_price(
+        &self,
+        item: &InventoryItem,
+        market: &MarketPrices,
+    ) -> Result<Option<f64>, String> {
+        let rule = self.rule.as_ref().ok_or("missing pricing rule")?;
+        let price = match rule {
+            PricingRule::Fixed { price } => *price,
+            PricingRule::BelowFloor { percent } => {
+                let floor = market.floor_price.ok_or("collection has no floor price")?;
+                floor * (1.0 - percent / 100.0)
+            }
+            PricingRule::BelowTraitFloor { percent } => {
+                let attributes = market
+                    .attributes
+                    .as_ref()
+                    .ok_or("collection has no attribute floors")?;
+                let floor = item
+                    .attributes
+                    .iter()
+                    .filter_map(|a| attributes.trait_floor(&a.trait_type, &a.value))
+                    .max_by(f64::total_cmp)
+                    .ok_or("no floor for any trait of the token")?;
+                floor * (1.0 - percent / 100.0)
+            }
+            PricingRule::Delist => return Ok(None),
+        };
+
+        let price = round_to_lamports(price);
+        match self.min_price {
+            Some(min_price) if price < min_price => Err(format!(
+                "target price {price} is below the minimum price {min_price}"
+            )),
+            _ if price <= 0.0 => Err(format!("target price {price} is not positive")),
+            _ => Ok(Some(price)),
+        }
+    }
+
+    /// Compare the current listings of the seller with the rule, without requesting any instruction.
+    /// `listings` are the current listings of the inventory tokens, e.g. from `Tokens::listings`.
+    pub fn diff(&self, listings: &[Listing], market: &MarketPrices) -> RepricePlan {
+        let changes = self
+            .items
+            .iter()
+            .map(|item| {
+                let listing = listings
+                    .iter()
+                    .find(|l| l.token_mint == item.token_mint && l.seller == self.seller)
+                    .cloned();
+
+                let action = match (self.target_price(item, market), &listing) {
+                    (Err(reason), _) => RepriceAction::Skip { reason },
+                    (Ok(None), None) => RepriceAction::Keep { price: None },
+                    (Ok(None), Some(listing)) => RepriceAction::Cancel {
+                        price: listing.price,
+                    },
+                    (Ok(Some(price)), None) => RepriceAction::List { price },
+                    (Ok(Some(price)), Some(listing))
+                        if round_to_lamports(listing.price) == price =>
+                    {
+                        RepriceAction::Keep { price: Some(price) }
+                    }
+                    (Ok(Some(price)), Some(listing)) => RepriceAction::ChangePrice {
+                        old: listing.price,
+                        new: price,
+                    },
+                };
+
+                RepriceChange {
+                    item: item.clone(),
+                    listing,
+                    action,
+                }
+            })
+            .collect();
+
+        RepricePlan { changes }
+    }
+}
+
+fn round_to_lamports(sol: f64) -> f64 {
+    (sol * LAMPORTS_PER_SOL).round() / LAMPORTS_PER_SOL
+}
+
+/// Dry-run diff between the current listings and the rule
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepricePlan {
+    pub changes: Vec<RepriceChange>,
+}
+
+impl RepricePlan {
+    /// Changes that require an instruction
+    pub fn actionable(&self) -> impl Iterator<Item = &RepriceChange> {
+        self.changes
+            .iter()
+            .filter(|change| change.action.is_actionable())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepriceChange {
+    pub item: InventoryItem,
+    /// Current listing of the token by the seller
+    pub listing: Option<Listing>,
+    pub action: RepriceAction,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RepriceAction {
+    /// List the unlisted token, via `Instructions::sell`
+    List { price: f64 },
+    /// Change the price of the listing, via `Instructions::sell_change_price`
+    ChangePrice { old: f64, new: f64 },
+    /// Cancel the listing, via `Instructions::sell_cancel`
+    Cancel { price: f64 },
+    /// Already in line with the rule
+    Keep { price: Option<f64> },
+    /// The rule could not be evaluated for the token
+    Skip { reason: String },
+}
+
+impl RepriceAction {
+    pub fn is_actionable(&self) -> bool {
+        matches!(
+            self,
+            RepriceAction::List { .. }
+                | RepriceAction::ChangePrice { .. }
+                | RepriceAction::Cancel { .. }
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepriceInstruction {
+    pub change: RepriceChange,
+    pub instruction: InstructionResponse,
+}
@@ -0,0 +1,144 @@
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::error::MagicedenError;
+
+use super::{AttributeFilter, InstructionResponse, Listing, RarityProvider, LAMPORTS_PER_SOL};
+
+#[derive(Clone, Serialize, Default, Debug, Builder, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[builder(name = "SweepRequestArgs")]
+#[builder(pattern = "mutable")]
+#[builder(setter(into, strip_option), default)]
+#[builder(derive(Debug))]
+#[builder(build_fn(error = "MagicedenError"))]
+pub struct SweepRequest {
+    /// Collection to sweep
+    pub collection_symbol: String,
+    /// Buyer wallet.
+    pub buyer: String,
+    /// Maximum total spent in SOL
+    pub budget: f64,
+    /// Only sweep listings at or below this price in SOL
+    pub max_price: Option<f64>,
+    /// Only sweep listings matching this attribute filter
+    pub attributes: Option<AttributeFilter>,
+    /// Only sweep listings ranked at or above this rank, unranked listings are skipped
+    pub max_rank: Option<i32>,
+    /// Rarity providers used for `max_rank`, defaults to `RarityProvider::DEFAULT_ORDER`
+    pub rarity_order: Option<Vec<RarityProvider>>,
+    /// Maximum number of items to buy
+    pub max_items: Option<usize>,
+    /// Maximum number of listings to scan, default 500
+    pub max_listings: Option<i64>,
+    /// Number of buy now instructions requested concurrently, default 4
+    pub concurrency: Option<usize>,
+    /// Buyer referral wallet. Option
+    pub buyer_referral: Option<String>,
+}
+
+impl SweepRequest {
+    /// Pick the cheapest eligible listings that fit in the budget.
+    ///
+    /// Returns the selected listings in ascending price order and every other listing with the reason it was skipped.
+    pub fn select(&self, mut listings: Vec<Listing>) -> (Vec<Listing>, Vec<SkippedListing>) {
+        listings.sort_by(|a, b| a.price.total_cmp(&b.price));
+
+        let order = self
+            .rarity_order
+            .as_deref()
+            .unwrap_or(&RarityProvider::DEFAULT_ORDER);
+        let mut remaining = to_lamports(self.budget);
+        let mut selected: Vec<Listing> = vec![];
+        let mut skipped = vec![];
+
+        for listing in listings {
+            let reason = if selected
+                .iter()
+                .any(|selected| selected.token_mint == listing.token_mint)
+            {
+                Some(SkipReason::Duplicate)
+            } else if listing.seller == self.buyer {
+                Some(SkipReason::OwnListing)
+            } else if self.max_price.is_some_and(|max| listing.price > max) {
+                Some(SkipReason::AboveMaxPrice)
+            } else if let Some(max_rank) = self.max_rank {
+                match listing.rarity.best_rank_by(order) {
+                    None => Some(SkipReason::Unranked),
+                    Some((_, rank)) if rank > max_rank => Some(SkipReason::RankTooLow { rank }),
+                    Some(_) => None,
+                }
+            } else {
+                None
+            }
+            .or_else(|| {
+                if self.max_items.is_some_and(|max| selected.len() >= max) {
+                    Some(SkipReason::MaxItemsReached)
+                } else if to_lamports(listing.price) > remaining {
+                    Some(SkipReason::OverBudget)
+                } else {
+                    None
+                }
+            });
+
+            match reason {
+                Some(reason) => skipped.push(SkippedListing { listing, reason }),
+                None => {
+                    remaining -= to_lamports(listing.price);
+                    selected.push(listing);
+                }
+            }
+        }
+
+        (selected, skipped)
+    }
+}
+
+fn to_lamports(sol: f64) -> i64 {
+    (sol * LAMPORTS_PER_SOL).round() as i64
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SweepPlan {
+    /// Listings to buy with their buy now instruction, in ascending price order
+    pub items: Vec<SweepItem>,
+    /// Total cost of `items` in SOL
+    pub total_cost: f64,
+    pub skipped: Vec<SkippedListing>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SweepItem {
+    pub listing: Listing,
+    pub instruction: InstructionResponse,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkippedListing {
+    pub listing: Listing,
+    pub reason: SkipReason,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SkipReason {
+    /// Price is above the remaining budget
+    OverBudget,
+    /// Price is above `SweepRequest::max_price`
+    AboveMaxPrice,
+    /// `SweepRequest::max_items` listings were already selected
+    MaxItemsReached,
+    /// Rank is above `SweepRequest::max_rank`
+    RankTooLow { rank: i32 },
+    /// No rank is available from the configured rarity providers
+    Unranked,
+    /// The listing is owned by the buyer
+    OwnListing,
+    /// The token was already selected from another listing
+    Duplicate,
+    /// Requesting the buy now instruction failed
+    InstructionFailed(String),
+}
@@ -1,3 +1,22 @@
+use serde::{Deserialize, Serialize};
+
 use super::Listing;
 
 pub type TokenListingsResponse = Vec<Listing>;
+
+pub type TokenOffersReceivedResponse = Vec<Offer>;
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Offer {
+    pub pda_address: String,
+    pub token_mint: String,
+    pub auction_house: String,
+    pub buyer: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub buyer_referral: Option<String>,
+    pub token_size: i64,
+    /// Price in SOL
+    pub price: f64,
+    pub expiry: i64,
+}
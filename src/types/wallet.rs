@@ -6,3 +6,10 @@ pub struct WalletInfoResponse {
     pub display_name: String,
     pub avatar: String,
 }
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletEscrowBalanceResponse {
+    /// Escrow balance in SOL
+    pub buyer_escrow: f64,
+}
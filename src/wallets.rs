@@ -1,4 +1,9 @@
-use crate::{error::MagicedenError, types::WalletInfoResponse, Client};
+use crate::{
+    client::endpoint,
+    error::MagicedenError,
+    types::{RequestOptions, WalletEscrowBalanceResponse, WalletInfoResponse},
+    Client,
+};
 
 pub struct Wallets<'c> {
     pub client: &'c Client,
@@ -12,6 +17,45 @@ impl<'c> Wallets<'c> {
     /// Get info about the wallet owner.
     /// @path: /wallets/{wallet_address}
     pub async fn info(&self, wallet_address: &str) -> Result<WalletInfoResponse, MagicedenError> {
-        self.client.get(&format!("/wallets/{wallet_address}")).await
+        self.info_with_options(wallet_address, &RequestOptions::default())
+            .await
+    }
+
+    /// Same as [`Self::info`] with per-request [`RequestOptions`]
+    #[tracing::instrument(name = "info", skip(self, options))]
+    pub async fn info_with_options(
+        &self,
+        wallet_address: &str,
+        options: &RequestOptions,
+    ) -> Result<WalletInfoResponse, MagicedenError> {
+        self.client
+            .get_with_options(endpoint!("/wallets/{wallet_address}"), &(), options)
+            .await
+    }
+
+    /// Get escrow balance of a wallet.
+    /// @path: /wallets/{wallet_address}/escrow_balance
+    pub async fn escrow_balance(
+        &self,
+        wallet_address: &str,
+    ) -> Result<WalletEscrowBalanceResponse, MagicedenError> {
+        self.escrow_balance_with_options(wallet_address, &RequestOptions::default())
+            .await
+    }
+
+    /// Same as [`Self::escrow_balance`] with per-request [`RequestOptions`]
+    #[tracing::instrument(name = "escrow_balance", skip(self, options))]
+    pub async fn escrow_balance_with_options(
+        &self,
+        wallet_address: &str,
+        options: &RequestOptions,
+    ) -> Result<WalletEscrowBalanceResponse, MagicedenError> {
+        self.client
+            .get_with_options(
+                endpoint!("/wallets/{wallet_address}/escrow_balance"),
+                &(),
+                options,
+            )
+            .await
     }
 }
@@ -0,0 +1,213 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    time::Duration,
+};
+
+use backoff::backoff::Backoff;
+use futures::{stream, Stream};
+
+use crate::{
+    error::MagicedenError,
+    types::{
+        CollectionActivitiesRequestArgs, CollectionActivitiy, CollectionListingsRequest,
+        ListingEvent, ListingTracker,
+    },
+    Client,
+};
+
+const ACTIVITIES_PAGE_SIZE: i64 = 100;
+/// Maximum number of pages fetched to close a gap between two polls
+const MAX_GAP_PAGES: i64 = 10;
+/// Number of signatures remembered for de-duplication
+const SEEN_CAPACITY: usize = 10_000;
+
+/// Bounded set of the most recently seen signatures
+struct Seen {
+    order: VecDeque<String>,
+    set: HashSet<String>,
+}
+
+impl Seen {
+    fn new() -> Self {
+        Self {
+            order: VecDeque::new(),
+            set: HashSet::new(),
+        }
+    }
+
+    fn contains(&self, signature: &str) -> bool {
+        self.set.contains(signature)
+    }
+
+    fn insert(&mut self, signature: String) {
+        if self.set.insert(signature.clone()) {
+            self.order.push_back(signature);
+        }
+        while self.order.len() > SEEN_CAPACITY {
+            if let Some(oldest) = self.order.pop_front() {
+                self.set.remove(&oldest);
+            }
+        }
+    }
+}
+
+/// Polling state shared by the watch streams
+struct Poller<'c, S, T> {
+    client: &'c Client,
+    symbol: String,
+    interval: Duration,
+    backoff: backoff::ExponentialBackoff,
+    delay: Option<Duration>,
+    started: bool,
+    pending: VecDeque<Result<T, MagicedenError>>,
+    state: S,
+}
+
+impl<'c, S, T> Poller<'c, S, T> {
+    fn new(client: &'c Client, symbol: &str, interval: Duration, state: S) -> Self {
+        let mut backoff = client.backoff().clone();
+        backoff.reset();
+        Self {
+            client,
+            symbol: symbol.to_string(),
+            interval,
+            backoff,
+            delay: None,
+            started: false,
+            pending: VecDeque::new(),
+            state,
+        }
+    }
+
+    /// Wait for the next poll: the interval, or the backoff delay after an error
+    async fn wait(&mut self) {
+        if self.started || self.delay.is_some() {
+            tokio::time::sleep(self.delay.take().unwrap_or(self.interval)).await;
+        }
+    }
+
+    fn push(&mut self, result: Result<Vec<T>, MagicedenError>) {
+        match result {
+            Ok(items) => {
+                self.backoff.reset();
+                self.pending.extend(items.into_iter().map(Ok));
+            }
+            Err(e) => {
+                tracing::warn!("Polling {} failed: {e}", self.symbol);
+                self.delay = Some(
+                    self.backoff
+                        .next_backoff()
+                        .unwrap_or(self.backoff.max_interval),
+                );
+                self.pending.push_back(Err(e));
+            }
+        }
+    }
+}
+
+pub(crate) fn activities<'c>(
+    client: &'c Client,
+    symbol: &str,
+    interval: Duration,
+) -> impl Stream<Item = Result<CollectionActivitiy, MagicedenError>> + 'c {
+    let poller = Poller::new(client, symbol, interval, Seen::new());
+
+    stream::unfold(poller, |mut poller| async move {
+        loop {
+            if let Some(item) = poller.pending.pop_front() {
+                return Some((item, poller));
+            }
+            poller.wait().await;
+            let result = poll_activities(&mut poller).await;
+            poller.push(result);
+        }
+    })
+}
+
+/// Fetch the activities newer than the last poll, paging back until a known signature is met.
+/// The first poll only records the current activities.
+async fn poll_activities(
+    poller: &mut Poller<'_, Seen, CollectionActivitiy>,
+) -> Result<Vec<CollectionActivitiy>, MagicedenError> {
+    let collections = poller.client.collections();
+    let mut new = vec![];
+    let mut signatures = HashSet::new();
+    let mut offset = 0;
+
+    for _ in 0..MAX_GAP_PAGES {
+        let request = CollectionActivitiesRequestArgs::default()
+            .offset(offset)
+            .limit(ACTIVITIES_PAGE_SIZE)
+            .build()?;
+        let page = collections.activities(&poller.symbol, request).await?;
+        let len = page.len() as i64;
+
+        let mut met_known = false;
+        for activity in page {
+            if poller.state.contains(&activity.signature) {
+                met_known = true;
+                break;
+            }
+            if signatures.insert(activity.signature.clone()) {
+                new.push(activity);
+            }
+        }
+
+        if met_known || !poller.started || len < ACTIVITIES_PAGE_SIZE {
+            break;
+        }
+        offset += len;
+    }
+
+    // Activities are returned newest first, emit them in chronological order
+    new.reverse();
+    for activity in &new {
+        poller.state.insert(activity.signature.clone());
+    }
+
+    if !poller.started {
+        poller.started = true;
+        return Ok(vec![]);
+    }
+    Ok(new)
+}
+
+pub(crate) fn listings<'c>(
+    client: &'c Client,
+    symbol: &str,
+    request: CollectionListingsRequest,
+    interval: Duration,
+) -> impl Stream<Item = Result<ListingEvent, MagicedenError>> + 'c {
+    let poller = Poller::new(client, symbol, interval, ListingTracker::default());
+
+    stream::unfold((poller, request), |(mut poller, request)| async move {
+        loop {
+            if let Some(item) = poller.pending.pop_front() {
+                return Some((item, (poller, request)));
+            }
+            poller.wait().await;
+            let result = poll_listings(&mut poller, &request).await;
+            poller.push(result);
+        }
+    })
+}
+
+/// Fetch the listings and diff them with the previous poll.
+/// The first poll only records the current listings.
+async fn poll_listings(
+    poller: &mut Poller<'_, ListingTracker, ListingEvent>,
+    request: &CollectionListingsRequest,
+) -> Result<Vec<ListingEvent>, MagicedenError> {
+    let listings = poller
+        .client
+        .collections()
+        .listings(&poller.symbol, request.clone())
+        .await?;
+    let events = poller.state.update(listings);
+
+    if !poller.started {
+        poller.started = true;
+        return Ok(vec![]);
+    }
+    Ok(events)
+}
@@ -0,0 +1,121 @@
+use std::sync::{Arc, Mutex};
+
+use magiceden_rs::{error::MagicedenError, ApiKeys, Auth, Client, API_KEY_ENV, API_KEY_FILE_ENV};
+use reqwest::header::HeaderName;
+
+mod common;
+
+type Headers = Arc<Mutex<Vec<Vec<(String, String)>>>>;
+
+/// Serve stats and record the headers of every request
+fn serve() -> (String, Headers) {
+    let headers = Headers::default();
+    let base = common::serve_raw({
+        let headers = headers.clone();
+        move |request| {
+            headers.lock().unwrap().push(request.headers.clone());
+            (
+                200,
+                "application/json",
+                r#"{"symbol":"okay_bears"}"#.to_string(),
+            )
+        }
+    });
+    (base, headers)
+}
+
+async fn sent_header(client: Client, headers: &Headers, name: &str) -> Option<String> {
+    client.collections().stats("okay_bears").await.unwrap();
+    let headers = headers.lock().unwrap();
+    headers
+        .last()
+        .unwrap()
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.clone())
+}
+
+#[tokio::test]
+async fn no_key_sends_no_authorization() {
+    let (base, headers) = serve();
+    let client = Client::new().with_api_base(base);
+    assert_eq!(sent_header(client, &headers, "authorization").await, None);
+
+    let (base, headers) = serve();
+    let client = Client::new().with_api_base(base).with_api_key("").unwrap();
+    assert_eq!(sent_header(client, &headers, "authorization").await, None);
+}
+
+#[tokio::test]
+async fn key_is_sent_with_the_auth_strategy() {
+    let (base, headers) = serve();
+    let client = Client::new()
+        .with_api_base(&base)
+        .with_api_key("key")
+        .unwrap();
+    assert_eq!(
+        sent_header(client.clone(), &headers, "authorization").await,
+        Some("Bearer key".to_string())
+    );
+
+    let custom = client.with_auth(Auth::Header(HeaderName::from_static("x-api-key")));
+    assert_eq!(
+        sent_header(custom.clone(), &headers, "x-api-key").await,
+        Some("key".to_string())
+    );
+    assert_eq!(sent_header(custom, &headers, "authorization").await, None);
+
+    let none = Client::new()
+        .with_api_base(base)
+        .with_api_key("key")
+        .unwrap()
+        .with_auth(Auth::None);
+    assert_eq!(sent_header(none, &headers, "authorization").await, None);
+}
+
+#[test]
+fn invalid_keys_are_rejected() {
+    let error = Client::new().with_api_key("bad\nkey").unwrap_err();
+    assert!(
+        matches!(error, MagicedenError::InvalidArgument(_)),
+        "{error}"
+    );
+    assert!(!error.to_string().contains("bad"), "{error}");
+
+    let error = ApiKeys::new(["key", ""]).unwrap_err();
+    assert!(error.to_string().contains("API key 1"), "{error}");
+}
+
+#[test]
+fn debug_redacts_keys() {
+    let client = Client::new().with_api_key("secret-key").unwrap();
+    let debug = format!("{client:?}");
+    assert!(!debug.contains("secret-key"), "{debug}");
+    assert!(debug.contains("***"), "{debug}");
+
+    let key = client.api_key().unwrap();
+    assert_eq!(key.to_string(), "***");
+    assert_eq!(key.expose_secret(), "secret-key");
+}
+
+#[test]
+fn from_env_reads_key_or_key_file() {
+    std::env::remove_var(API_KEY_ENV);
+    std::env::remove_var(API_KEY_FILE_ENV);
+    assert!(Client::from_env().unwrap().api_key().is_none());
+
+    let path = std::env::temp_dir().join(format!("magiceden-key-{}", std::process::id()));
+    std::fs::write(&path, "file-key\n").unwrap();
+    std::env::set_var(API_KEY_FILE_ENV, &path);
+    let client = Client::from_env().unwrap();
+    assert_eq!(client.api_key().unwrap().expose_secret(), "file-key");
+
+    std::env::set_var(API_KEY_ENV, "env-key");
+    let client = Client::from_env().unwrap();
+    assert_eq!(client.api_key().unwrap().expose_secret(), "env-key");
+
+    std::env::remove_var(API_KEY_ENV);
+    std::fs::remove_file(&path).unwrap();
+    assert!(matches!(Client::from_env(), Err(MagicedenError::Config(_))));
+    std::env::remove_var(API_KEY_FILE_ENV);
+}
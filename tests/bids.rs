@@ -0,0 +1,256 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use magiceden_rs::{
+    types::{
+        ActiveBid, BidAction, BidManager, BidStep, BidTarget, CancelReason, EscrowAction, Offer,
+    },
+    Client,
+};
+
+mod common;
+
+const BUYER: &str = "buyer";
+
+fn offer(token_mint: &str, buyer: &str, price: f64) -> Offer {
+    Offer {
+        pda_address: format!("pda-{token_mint}-{buyer}"),
+        token_mint: token_mint.to_string(),
+        auction_house: "E8cU1WiRWjanGxmn96ewBgk9vPTcL6AEZ1t6F6fkgUWe".to_string(),
+        buyer: buyer.to_string(),
+        buyer_referral: None,
+        token_size: 1,
+        price,
+        expiry: 0,
+    }
+}
+
+fn manager() -> BidManager {
+    let mut manager = BidManager::new(BUYER, "E8cU1WiRWjanGxmn96ewBgk9vPTcL6AEZ1t6F6fkgUWe")
+        .with_targets(vec![
+            BidTarget::new("a", 1.0, 5.0),
+            BidTarget::new("b", 1.0, 5.0),
+            BidTarget::new("c", 1.0, 2.0),
+            BidTarget::new("d", 1.0, 5.0),
+        ])
+        .with_increment(0.1)
+        .with_bid_duration(3600);
+    manager.bids = vec![
+        ActiveBid {
+            token_mint: "b".to_string(),
+            price: 2.0,
+            expiry: 0,
+        },
+        ActiveBid {
+            token_mint: "c".to_string(),
+            price: 1.5,
+            expiry: 0,
+        },
+        ActiveBid {
+            token_mint: "d".to_string(),
+            price: 1.0,
+            expiry: 100,
+        },
+        ActiveBid {
+            token_mint: "stale".to_string(),
+            price: 3.0,
+            expiry: 0,
+        },
+    ];
+    manager
+}
+
+fn offers() -> HashMap<String, Vec<Offer>> {
+    HashMap::from([
+        ("a".to_string(), vec![]),
+        (
+            "b".to_string(),
+            vec![offer("b", BUYER, 2.0), offer("b", "other", 2.4)],
+        ),
+        ("c".to_string(), vec![offer("c", "other", 2.0)]),
+        ("d".to_string(), vec![]),
+    ])
+}
+
+#[test]
+fn bids_reconcile() {
+    let manager = manager();
+    let plan = manager.reconcile(&offers(), 1.0, 200);
+
+    assert_eq!(
+        plan.actions,
+        vec![
+            BidAction::Cancel {
+                token_mint: "d".to_string(),
+                price: 1.0,
+                reason: CancelReason::Expired
+            },
+            BidAction::Cancel {
+                token_mint: "stale".to_string(),
+                price: 3.0,
+                reason: CancelReason::Untargeted
+            },
+            BidAction::Place {
+                token_mint: "a".to_string(),
+                price: 1.0
+            },
+            BidAction::ChangePrice {
+                token_mint: "b".to_string(),
+                old: 2.0,
+                new: 2.5
+            },
+            BidAction::Cancel {
+                token_mint: "c".to_string(),
+                price: 1.5,
+                reason: CancelReason::AboveMaxPrice
+            },
+            BidAction::Place {
+                token_mint: "d".to_string(),
+                price: 1.0
+            },
+        ]
+    );
+    assert_eq!(plan.required_escrow, 2.5);
+    assert_eq!(plan.escrow, Some(EscrowAction::Deposit { amount: 1.5 }));
+}
+
+#[test]
+fn bids_apply_and_resume() {
+    let mut manager = manager().with_withdraw_excess(true);
+    let plan = manager.reconcile(&offers(), 1.0, 200);
+    assert_eq!(plan.expiry, Some(3800));
+    manager.apply(&plan);
+
+    let mut bids: Vec<(String, f64, i64)> = manager
+        .bids
+        .iter()
+        .map(|bid| (bid.token_mint.clone(), bid.price, bid.expiry))
+        .collect();
+    bids.sort_by(|a, b| a.0.cmp(&b.0));
+    assert_eq!(
+        bids,
+        vec![
+            ("a".to_string(), 1.0, 3800),
+            ("b".to_string(), 2.5, 3800),
+            ("d".to_string(), 1.0, 3800),
+        ]
+    );
+
+    let json = serde_json::to_string(&manager).unwrap();
+    let resumed: BidManager = serde_json::from_str(&json).unwrap();
+    assert_eq!(resumed, manager);
+
+    let plan = resumed.reconcile(&offers(), 4.0, 300);
+    assert!(plan.actions.is_empty());
+    assert_eq!(plan.escrow, Some(EscrowAction::Withdraw { amount: 1.5 }));
+}
+
+#[tokio::test]
+async fn bids_plan_and_instructions() {
+    let instructions = Arc::new(Mutex::new(vec![]));
+    let base = common::serve({
+        let instructions = instructions.clone();
+        move |path| {
+            let body = match path.split('?').next().unwrap() {
+                "/tokens/a/offers_received" | "/tokens/stale/offers_received" => "[]".to_string(),
+                "/tokens/b/offers_received" => {
+                    serde_json::to_string(&[offer("b", BUYER, 2.0), offer("b", "other", 2.4)])
+                        .unwrap()
+                }
+                "/wallets/buyer/escrow_balance" => r#"{"buyerEscrow":1.0}"#.to_string(),
+                endpoint => {
+                    assert!(endpoint.starts_with("/instructions/"), "{path}");
+                    instructions.lock().unwrap().push(path.to_string());
+                    r#"{"tx":{"type":"Buffer","data":[1]},"txSigned":{"type":"Buffer","data":[2]}}"#
+                        .to_string()
+                }
+            };
+            (200, body)
+        }
+    });
+    let client = Client::new().with_api_base(base);
+
+    let mut manager = BidManager::new(BUYER, "house")
+        .with_targets(vec![
+            BidTarget::new("a", 1.0, 5.0),
+            BidTarget::new("b", 1.0, 5.0),
+        ])
+        .with_increment(0.1)
+        .with_bid_duration(3600);
+    manager.bids = vec![
+        ActiveBid {
+            token_mint: "b".to_string(),
+            price: 2.0,
+            expiry: 0,
+        },
+        ActiveBid {
+            token_mint: "stale".to_string(),
+            price: 3.0,
+            expiry: 0,
+        },
+    ];
+
+    let before = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64;
+    let plan = client.bids().plan(&manager).await.unwrap();
+    let expiry = plan.expiry.unwrap();
+    assert!(
+        expiry >= before + 3600 && expiry <= before + 3660,
+        "{expiry}"
+    );
+    assert_eq!(plan.escrow, Some(EscrowAction::Deposit { amount: 1.5 }));
+
+    let sent = client.bids().instructions(&manager, &plan).await.unwrap();
+    let steps: Vec<BidStep> = sent.into_iter().map(|sent| sent.step).collect();
+    assert_eq!(
+        steps,
+        vec![
+            BidStep::Escrow(EscrowAction::Deposit { amount: 1.5 }),
+            BidStep::Bid(BidAction::Cancel {
+                token_mint: "stale".to_string(),
+                price: 3.0,
+                reason: CancelReason::Untargeted
+            }),
+            BidStep::Bid(BidAction::Place {
+                token_mint: "a".to_string(),
+                price: 1.0
+            }),
+            BidStep::Bid(BidAction::ChangePrice {
+                token_mint: "b".to_string(),
+                old: 2.0,
+                new: 2.5
+            }),
+        ]
+    );
+    let paths = instructions.lock().unwrap();
+    assert!(
+        paths[0]
+            .starts_with("/instructions/deposit?buyer=buyer&auctionHouseAddress=house&amount=1.5"),
+        "{}",
+        paths[0]
+    );
+    assert!(
+        paths[1].starts_with("/instructions/buy_cancel?"),
+        "{}",
+        paths[1]
+    );
+    assert!(paths[2].starts_with("/instructions/buy?"), "{}", paths[2]);
+    assert!(
+        paths[3].starts_with("/instructions/buy_change_price?"),
+        "{}",
+        paths[3]
+    );
+    // The expiry sent is the one recorded by `apply`
+    for path in &paths[2..] {
+        assert!(path.contains(&format!("expiry={expiry}")), "{path}");
+    }
+
+    manager.apply(&plan);
+    assert!(manager.bids.iter().all(|bid| bid.expiry == expiry));
+    assert_eq!(manager.bids.len(), 2);
+}
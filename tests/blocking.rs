@@ -0,0 +1,56 @@
+#![cfg(feature = "blocking")]
+
+use std::time::Duration;
+
+use magiceden_rs::{blocking::Client, types::CollectionActivitiesRequestArgs};
+
+mod common;
+
+#[test]
+fn blocking_collection_stats() {
+    let base = common::serve(|path| {
+        assert_eq!(path, "/collections/okay_bears/stats");
+        (
+            200,
+            r#"{"symbol":"okay_bears","floorPrice":1500000000,"listedCount":10,"avgPrice24hr":1.0,"volumeAll":100.0}"#.to_string(),
+        )
+    });
+
+    let client = Client::try_from(magiceden_rs::Client::new().with_api_base(base)).unwrap();
+    let stats = client.collections().stats("okay_bears").unwrap();
+
+    assert_eq!(stats.symbol, "okay_bears");
+    assert_eq!(stats.floor_price_sol(), Some(1.5));
+}
+
+#[test]
+fn blocking_errors_and_queries() {
+    let base = common::serve(|path| {
+        match path {
+        "/wallets/unknown" => (400, r#"{"errors":[{"msg":"invalid wallet"}]}"#.to_string()),
+        _ => (
+            200,
+            r#"[{"signature":"sig1","type":"list","source":"magiceden_v2","slot":1,"blockTime":1,"buyerReferral":"","price":1.0}]"#.to_string(),
+        ),
+    }
+    });
+
+    let backoff = backoff::ExponentialBackoff {
+        max_elapsed_time: Some(Duration::ZERO),
+        ..Default::default()
+    };
+    let client = Client::new()
+        .unwrap()
+        .with_api_base(base)
+        .with_backoff(backoff);
+    assert!(client.wallets().info("unknown").is_err());
+
+    let activities = client
+        .collections()
+        .activities(
+            "okay_bears",
+            CollectionActivitiesRequestArgs::default().build().unwrap(),
+        )
+        .unwrap();
+    assert_eq!(activities.len(), 1);
+}
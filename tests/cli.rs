@@ -0,0 +1,148 @@
+#![cfg(feature = "cli")]
+
+use std::process::Command;
+
+mod common;
+
+fn magiceden(base: &str, args: &[&str]) -> (bool, String, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_magiceden"))
+        .args(args)
+        .env("MAGICEDEN_API_BASE", base)
+        .env("MAGICEDEN_API_KEY", "secret")
+        .env_remove("MAGICEDEN_CONFIG")
+        .env("XDG_CONFIG_HOME", "/nonexistent")
+        .output()
+        .unwrap();
+    (
+        output.status.success(),
+        String::from_utf8(output.stdout).unwrap(),
+        String::from_utf8(output.stderr).unwrap(),
+    )
+}
+
+fn listings_server() -> String {
+    common::serve(|path| {
+        assert_eq!(
+            path,
+            "/collections/okay_bears/listings?offset=20&limit=50&sort=listPrice"
+        );
+        let rarity = r#"{"moonrank": {"crawl": {"complete": true, "id": "1"}, "absolute_rarity": 5, "rank": 3}}"#;
+        let listings = vec![
+            common::listing_of("mint1", "seller1", 1.5, rarity),
+            common::listing_of("mint2", "seller2", 2.25, "{}"),
+        ];
+        (200, serde_json::to_string(&listings).unwrap())
+    })
+}
+
+#[test]
+fn cli_listings_csv() {
+    let base = listings_server();
+    let (success, stdout, stderr) = magiceden(
+        &base,
+        &[
+            "collections",
+            "listings",
+            "okay_bears",
+            "--offset",
+            "20",
+            "--limit",
+            "50",
+            "--sort",
+            "listPrice",
+            "--output",
+            "csv",
+        ],
+    );
+    assert!(success, "{stderr}");
+
+    let mut lines = stdout.lines();
+    let header: Vec<&str> = lines.next().unwrap().split(',').collect();
+    assert!(header.contains(&"rarity.moonrank.rank"));
+    let price = header.iter().position(|column| *column == "price").unwrap();
+    let prices: Vec<&str> = lines
+        .map(|line| line.split(',').nth(price).unwrap())
+        .collect();
+    assert_eq!(prices, ["1.5", "2.25"]);
+}
+
+#[test]
+fn cli_listings_table_and_json() {
+    let base = listings_server();
+    let args = [
+        "collections",
+        "listings",
+        "okay_bears",
+        "--offset",
+        "20",
+        "--limit",
+        "50",
+        "--sort",
+        "listPrice",
+    ];
+
+    let (success, stdout, stderr) = magiceden(&base, &[&args[..], &["-o", "table"]].concat());
+    assert!(success, "{stderr}");
+    assert_eq!(stdout.lines().count(), 3);
+    assert!(stdout.lines().next().unwrap().contains("tokenMint"));
+
+    let (success, stdout, stderr) = magiceden(&base, &args);
+    assert!(success, "{stderr}");
+    let listings: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(listings[1]["tokenMint"], "mint2");
+}
+
+#[test]
+fn cli_params_build_request() {
+    let base = common::serve(|path| {
+        assert_eq!(path, "/mmm/pools?owner=owner1&limit=5");
+        (200, "[]".to_string())
+    });
+    let (success, stdout, stderr) = magiceden(
+        &base,
+        &["mmm", "pools", "-p", "owner=owner1", "--limit", "5"],
+    );
+    assert!(success, "{stderr}");
+    assert_eq!(stdout.trim(), "[]");
+
+    let (success, _, stderr) = magiceden(&base, &["instructions", "buy-now", "-p", "price=1"]);
+    assert!(!success);
+    assert!(stderr.starts_with("error: missing field"), "{stderr}");
+}
+
+#[test]
+fn cli_flags_build_request() {
+    let base = common::serve(|path| {
+        assert_eq!(path, "/mmm/pools?collectionSymbol=okay_bears&owner=owner1");
+        (200, "[]".to_string())
+    });
+    let (success, stdout, stderr) = magiceden(
+        &base,
+        &[
+            "mmm",
+            "pools",
+            "--owner",
+            "owner1",
+            "--collection-symbol",
+            "okay_bears",
+        ],
+    );
+    assert!(success, "{stderr}");
+    assert_eq!(stdout.trim(), "[]");
+
+    let (success, _, stderr) = magiceden(&base, &["mmm", "pools", "--owner", "a", "--sort", "x"]);
+    assert!(!success);
+    assert!(
+        stderr.contains("--sort is not supported by this command"),
+        "{stderr}"
+    );
+    let (success, _, stderr) = magiceden(
+        &base,
+        &["launchpad", "collections", "--sort-direction", "asc"],
+    );
+    assert!(!success);
+    assert!(
+        stderr.contains("--sort-direction is not supported"),
+        "{stderr}"
+    );
+}
@@ -0,0 +1,156 @@
+use std::sync::{Arc, Mutex};
+
+use magiceden_rs::{
+    error::MagicedenError, types::InstructionsBuyRequestArgs, Client, ClientConfig, Cluster,
+    API_BASE, AUCTION_HOUSE_ADDRESS, DEVNET_API_BASE, MMM_PROGRAM_ID,
+};
+
+mod common;
+
+#[test]
+fn cluster_defaults() {
+    assert_eq!(Cluster::default(), Cluster::Mainnet);
+    assert_eq!(Cluster::Mainnet.api_base(), API_BASE);
+    assert_eq!(
+        Cluster::Devnet.api_base(),
+        "https://api-devnet.magiceden.dev/v2"
+    );
+    let custom = Cluster::Custom("http://localhost:8899/v2".to_string());
+    assert_eq!(custom.api_base(), "http://localhost:8899/v2");
+
+    assert_eq!(
+        Cluster::Mainnet.auction_house_address(),
+        Some("E8cU1WiRWjanGxmn96ewBgk9vPTcL6AEZ1t6F6fkgUWe")
+    );
+    assert_eq!(Cluster::Devnet.auction_house_address(), None);
+    assert_eq!(custom.auction_house_address(), None);
+
+    assert_eq!(
+        Cluster::Mainnet.mmm_program_id(),
+        Some("mmm3XBJg5gk8XJxEKBvdgptZz6SgK4tXvn36sodowMc")
+    );
+    assert_eq!(Cluster::Devnet.mmm_program_id(), Some(MMM_PROGRAM_ID));
+    assert_eq!(custom.mmm_program_id(), None);
+
+    assert_eq!("devnet".parse::<Cluster>().unwrap(), Cluster::Devnet);
+    assert_eq!("mainnet-beta".parse::<Cluster>().unwrap(), Cluster::Mainnet);
+    assert_eq!(
+        "https://relay.example/v2".parse::<Cluster>().unwrap(),
+        Cluster::Custom("https://relay.example/v2".to_string())
+    );
+    assert!("testnet".parse::<Cluster>().is_err());
+}
+
+#[test]
+fn client_cluster_follows_api_base() {
+    let client = Client::new();
+    assert_eq!(client.cluster(), Cluster::Mainnet);
+    assert_eq!(client.auction_house_address(), Some(AUCTION_HOUSE_ADDRESS));
+
+    let client = Client::new().with_cluster(Cluster::Devnet);
+    assert_eq!(client.api_base(), DEVNET_API_BASE);
+    assert_eq!(client.cluster(), Cluster::Devnet);
+    assert_eq!(client.auction_house_address(), None);
+    let client = client.with_auction_house_address("devnet-house");
+    assert_eq!(client.auction_house_address(), Some("devnet-house"));
+
+    let client = Client::new().with_api_base(DEVNET_API_BASE);
+    assert_eq!(client.cluster(), Cluster::Devnet);
+
+    let client = ClientConfig::from_json(
+        r#"{"cluster": "devnet", "api_base": "https://api-devnet.magiceden.dev/v2", "auction_house_address": "devnet-house"}"#,
+    )
+    .unwrap()
+    .build()
+    .unwrap();
+    assert_eq!(client.api_base(), DEVNET_API_BASE);
+    assert_eq!(client.auction_house_address(), Some("devnet-house"));
+}
+
+#[test]
+fn config_rejects_api_base_of_another_cluster() {
+    let config = ClientConfig {
+        cluster: Some(Cluster::Devnet),
+        api_base: Some(API_BASE.to_string()),
+        ..Default::default()
+    };
+    let error = config.build().unwrap_err();
+    assert!(matches!(error, MagicedenError::Config(_)), "{error}");
+    assert!(error.to_string().contains(API_BASE), "{error}");
+}
+
+fn serve() -> (String, Arc<Mutex<Vec<String>>>) {
+    let paths = Arc::new(Mutex::new(vec![]));
+    let base = common::serve({
+        let paths = paths.clone();
+        move |path| {
+            paths.lock().unwrap().push(path.to_string());
+            (
+                200,
+                r#"{"tx":{"type":"Buffer","data":[1]},"txSigned":{"type":"Buffer","data":[2]}}"#
+                    .to_string(),
+            )
+        }
+    });
+    (base, paths)
+}
+
+fn buy_request(auction_house_address: &str) -> magiceden_rs::types::InstructionsBuyRequest {
+    InstructionsBuyRequestArgs::default()
+        .buyer("buyer")
+        .auction_house_address(auction_house_address)
+        .token_mint("mint")
+        .price(1.5)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn instructions_default_to_the_client_auction_house() {
+    let (base, paths) = serve();
+    let client = Client::new()
+        .with_cluster(Cluster::Custom(base))
+        .with_auction_house_address("test-house");
+
+    client.instructions().buy(buy_request("")).await.unwrap();
+    client
+        .instructions()
+        .buy(buy_request("house"))
+        .await
+        .unwrap();
+
+    let paths = paths.lock().unwrap();
+    assert_eq!(
+        paths[0],
+        "/instructions/buy?buyer=buyer&auctionHouseAddress=test-house&tokenMint=mint&price=1.5"
+    );
+    assert!(
+        paths[1].contains("auctionHouseAddress=house&"),
+        "{}",
+        paths[1]
+    );
+}
+
+#[tokio::test]
+async fn instructions_without_auction_house_off_mainnet_fail() {
+    let (base, paths) = serve();
+    let client = Client::new().with_cluster(Cluster::Custom(base));
+
+    let error = client
+        .instructions()
+        .buy(buy_request(""))
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(error, MagicedenError::InvalidArgument(_)),
+        "{error}"
+    );
+    assert!(paths.lock().unwrap().is_empty());
+
+    client
+        .instructions()
+        .buy(buy_request("house"))
+        .await
+        .unwrap();
+    assert_eq!(paths.lock().unwrap().len(), 1);
+}
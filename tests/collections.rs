@@ -1,6 +1,67 @@
+use magiceden_rs::types::{CollectionAttributesResponse, HolderStatsResponse};
+
 mod common;
 
 #[tokio::test]
 async fn collection_get() {
     // let client = common::setup_client();
 }
+
+#[test]
+fn collection_holder_stats_concentration() {
+    let body = r#"{
+        "symbol": "okay_bears",
+        "totalSupply": 100,
+        "uniqueHolders": 4,
+        "tokenHistogram": { "bars": [{ "l_val": 10, "hight": 3 }, { "l_val": 70, "hight": 1 }] },
+        "topHolders": [
+            { "owner": "A", "tokens": 10 },
+            { "owner": "B", "tokens": 70, "ownerDisplay": { "sol": "whale.sol" } },
+            { "owner": "C", "tokens": 10 },
+            { "owner": "D", "tokens": 10 }
+        ]
+    }"#;
+    let stats: HolderStatsResponse = serde_json::from_str(body).unwrap();
+
+    assert_eq!(stats.top_n_share(1), Some(0.7));
+    assert_eq!(stats.top10_share(), Some(1.0));
+    // holdings [10, 10, 10, 70]: sum |xi - xj| = 6 * 60 = 360, 2 * n^2 * mean = 2 * 16 * 25 = 800
+    assert!((stats.gini().unwrap() - 0.45).abs() < 1e-9);
+
+    let tiers = stats.owner_tiers().unwrap();
+    assert_eq!(tiers[2].holders, 3);
+    assert_eq!(tiers[2].tokens, 30);
+    assert_eq!(tiers[4].holders, 1);
+    assert_eq!(tiers[0].holders, 0);
+}
+
+#[test]
+fn collection_holder_stats_without_distribution() {
+    let body = r#"{ "symbol": "okay_bears", "totalSupply": 100, "uniqueHolders": 4 }"#;
+    let stats: HolderStatsResponse = serde_json::from_str(body).unwrap();
+
+    assert_eq!(stats.top10_share(), None);
+    assert_eq!(stats.gini(), None);
+}
+
+#[test]
+fn collection_attributes_trait_floor() {
+    let body = r#"{
+        "results": {
+            "symbol": "okay_bears",
+            "availableAttributes": [
+                { "count": 12, "floor": 25000000000, "attribute": { "trait_type": "Fur", "value": "Gold" } },
+                { "count": 140, "floor": 19500000000, "attribute": { "trait_type": "Fur", "value": "Brown" } },
+                { "count": 30, "attribute": { "trait_type": "Hat", "value": "Crown" } }
+            ]
+        }
+    }"#;
+    let response: CollectionAttributesResponse = serde_json::from_str(body).unwrap();
+    let attributes = &response.results;
+
+    assert_eq!(attributes.trait_types(), vec!["Fur", "Hat"]);
+    assert_eq!(attributes.values("Fur").len(), 2);
+    assert_eq!(attributes.trait_floor("Fur", "Gold"), Some(25.0));
+    assert_eq!(attributes.trait_floor("Hat", "Crown"), None);
+    assert_eq!(attributes.trait_floor("Fur", "Blue"), None);
+}
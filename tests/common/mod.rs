@@ -1,6 +1,146 @@
-use magiceden_rs::Client;
+use magiceden_rs::{
+    types::{Listing, MmmPool},
+    Client,
+};
 
 #[allow(dead_code)]
 pub fn setup_client() -> Client {
-    Client::new().with_api_key("")
+    Client::new()
+}
+
+#[allow(dead_code)]
+pub fn listing(price: f64, rarity: &str) -> Listing {
+    listing_of("mint", "seller", price, rarity)
+}
+
+#[allow(dead_code)]
+pub fn listing_of(token_mint: &str, seller: &str, price: f64, rarity: &str) -> Listing {
+    let body = format!(
+        r#"{{
+            "pdaAddress": "pda-{token_mint}",
+            "auctionHouse": "E8cU1WiRWjanGxmn96ewBgk9vPTcL6AEZ1t6F6fkgUWe",
+            "tokenAddress": "ata-{token_mint}",
+            "tokenMint": "{token_mint}",
+            "seller": "{seller}",
+            "tokenSize": 1,
+            "price": {price},
+            "rarity": {rarity},
+            "extra": {{}},
+            "expiry": -1
+        }}"#
+    );
+    serde_json::from_str(&body).unwrap()
+}
+
+#[allow(dead_code)]
+pub fn pool(pool_key: &str, spot_price: f64) -> MmmPool {
+    serde_json::from_value(serde_json::json!({
+        "spotPrice": spot_price,
+        "curveType": "linear",
+        "curveDelta": 0.0,
+        "reinvestFulfillBuy": false,
+        "reinvestFulfillSell": false,
+        "expiry": 0,
+        "lpFeeBp": 0,
+        "buysideCreatorRoyaltyBp": 0,
+        "poolOwner": "owner",
+        "sellsideAssetAmount": 0,
+        "buysidePaymentAmount": 0,
+        "buyOrdersAmount": 0,
+        "collectionSymbol": "okay_bears",
+        "collectionName": "Okay Bears",
+        "poolType": "buy_sided",
+        "uuid": pool_key,
+        "poolKey": pool_key,
+        "cosigner": "",
+        "collectionSellerFeeBasisPoints": 0,
+        "lpFeeEarned": 0,
+        "updatedAt": "2023-01-01T00:00:00Z",
+    }))
+    .unwrap()
+}
+
+/// Request received by the local server
+#[allow(dead_code)]
+pub struct Request {
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+}
+
+#[allow(dead_code)]
+impl Request {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Serve `handler(path_and_query) -> (status, json body)` on a local port and return its base url
+#[allow(dead_code)]
+pub fn serve<F>(handler: F) -> String
+where
+    F: Fn(&str) -> (u16, String) + Send + Sync + 'static,
+{
+    serve_raw(move |request| {
+        let (status, body) = handler(&request.path);
+        (status, "application/json", body)
+    })
+}
+
+/// Serve `handler(request) -> (status, content type, body)` on a local port and return its base url
+#[allow(dead_code)]
+pub fn serve_raw<F>(handler: F) -> String
+where
+    F: Fn(&Request) -> (u16, &'static str, String) + Send + Sync + 'static,
+{
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        sync::Arc,
+    };
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let handler = Arc::new(handler);
+
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(mut stream) = stream else { continue };
+            let handler = handler.clone();
+            std::thread::spawn(move || {
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut headers = vec![];
+                loop {
+                    let mut line = String::new();
+                    if reader.read_line(&mut line).unwrap() == 0 || line == "\r\n" {
+                        break;
+                    }
+                    if let Some((key, value)) = line.trim_end().split_once(':') {
+                        headers.push((key.trim().to_string(), value.trim().to_string()));
+                    }
+                }
+
+                let request = Request {
+                    path: request_line
+                        .split_whitespace()
+                        .nth(1)
+                        .unwrap_or("/")
+                        .to_string(),
+                    headers,
+                };
+                let (status, content_type, body) = handler(&request);
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 {status} OK\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+            });
+        }
+    });
+
+    format!("http://{addr}")
 }
@@ -0,0 +1,124 @@
+use magiceden_rs::{error::MagicedenError, ClientConfig, RateLimitConfig};
+
+#[cfg(feature = "toml")]
+const TOML: &str = r#"
+api_key = "toml-key"
+api_keys = ["second-key"]
+key_selection = "least_used"
+api_base = "http://localhost:1/v2"
+timeout_ms = 5000
+proxy = "http://localhost:8080"
+
+[backoff]
+initial_interval_ms = 100
+max_elapsed_time_ms = 2000
+multiplier = 3.0
+
+[rate_limit]
+requests = 2
+"#;
+
+#[cfg(feature = "toml")]
+#[test]
+fn toml_config_builds_client() {
+    use std::time::Duration;
+
+    use magiceden_rs::KeySelection;
+
+    let config = ClientConfig::from_toml(TOML).unwrap();
+    assert_eq!(config.key_selection, Some(KeySelection::LeastUsed));
+    assert_eq!(
+        config.rate_limit,
+        Some(RateLimitConfig {
+            requests: 2,
+            period_ms: 1000
+        })
+    );
+
+    let client = config.build().unwrap();
+    assert_eq!(client.api_base(), "http://localhost:1/v2");
+    assert_eq!(client.api_key().unwrap().expose_secret(), "toml-key");
+    assert_eq!(client.api_keys().len(), 2);
+    assert_eq!(client.api_keys().selection(), KeySelection::LeastUsed);
+    let backoff = client.backoff();
+    assert_eq!(backoff.initial_interval, Duration::from_millis(100));
+    assert_eq!(backoff.current_interval, Duration::from_millis(100));
+    assert_eq!(backoff.max_elapsed_time, Some(Duration::from_secs(2)));
+    assert_eq!(backoff.multiplier, 3.0);
+    assert_eq!(client.ordinals_api_base(), magiceden_rs::ORDINALS_API_BASE);
+}
+
+#[test]
+fn json_config_and_files() {
+    let dir = std::env::temp_dir().join(format!("magiceden-config-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("key"), "file-key\n").unwrap();
+    std::fs::write(
+        dir.join("config.json"),
+        format!(
+            r#"{{"api_key_file": "{}", "evm_api_base": "http://localhost:2"}}"#,
+            dir.join("key").display()
+        ),
+    )
+    .unwrap();
+
+    let client = ClientConfig::from_file(dir.join("config.json"))
+        .unwrap()
+        .build()
+        .unwrap();
+    assert_eq!(client.api_key().unwrap().expose_secret(), "file-key");
+    assert_eq!(client.evm_api_base(), "http://localhost:2");
+
+    // Invalid with the `toml` feature, unsupported without
+    std::fs::write(dir.join("config.toml"), "timeout_ms = \"soon\"").unwrap();
+    let error = ClientConfig::from_file(dir.join("config.toml")).unwrap_err();
+    assert!(matches!(error, MagicedenError::Config(_)), "{error}");
+    assert!(error.to_string().contains("config.toml"), "{error}");
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn environment_overrides_config() {
+    std::env::set_var("MAGICEDEN_API_KEY", "env-key");
+    std::env::set_var("MAGICEDEN_API_BASE", "http://localhost:3/v2");
+    std::env::set_var("MAGICEDEN_BACKOFF_MAX_INTERVAL_MS", "750");
+    std::env::set_var("MAGICEDEN_RATE_LIMIT_PERIOD_MS", "60000");
+    let config = ClientConfig {
+        api_key: Some("file-key".into()),
+        rate_limit: Some(RateLimitConfig {
+            requests: 2,
+            period_ms: 1000,
+        }),
+        ..Default::default()
+    }
+    .with_env();
+    std::env::remove_var("MAGICEDEN_API_KEY");
+    std::env::remove_var("MAGICEDEN_API_BASE");
+    std::env::remove_var("MAGICEDEN_BACKOFF_MAX_INTERVAL_MS");
+    std::env::remove_var("MAGICEDEN_RATE_LIMIT_PERIOD_MS");
+
+    let config = config.unwrap();
+    assert_eq!(config.backoff.max_interval_ms, Some(750));
+    assert_eq!(config.rate_limit.as_ref().unwrap().period_ms, 60000);
+    let client = config.build().unwrap();
+    assert_eq!(client.api_key().unwrap().expose_secret(), "env-key");
+    assert_eq!(client.api_base(), "http://localhost:3/v2");
+
+    std::env::set_var("MAGICEDEN_TIMEOUT_MS", "soon");
+    let error = ClientConfig::from_env().unwrap_err();
+    std::env::remove_var("MAGICEDEN_TIMEOUT_MS");
+    assert!(
+        error.to_string().contains("MAGICEDEN_TIMEOUT_MS"),
+        "{error}"
+    );
+}
+
+#[test]
+fn invalid_proxy_is_rejected() {
+    let config = ClientConfig {
+        proxy: Some("not a url".to_string()),
+        ..Default::default()
+    };
+    assert!(matches!(config.build(), Err(MagicedenError::Reqwest(_))));
+}
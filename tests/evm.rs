@@ -0,0 +1,58 @@
+use magiceden_rs::{
+    types::{EvmChain, EvmCollectionsResponse},
+    Client,
+};
+
+mod common;
+
+#[test]
+fn evm_base_per_chain() {
+    let client = common::setup_client();
+    assert_eq!(
+        client.evm(EvmChain::Ethereum).base(),
+        "https://api-mainnet.magiceden.dev/v3/rtp/ethereum"
+    );
+    assert_eq!(
+        client.evm(EvmChain::Base).base(),
+        "https://api-mainnet.magiceden.dev/v3/rtp/base"
+    );
+
+    let client = Client::new().with_evm_api_base("http://localhost:8080/v3/rtp");
+    assert_eq!(
+        client.evm(EvmChain::Polygon).base(),
+        "http://localhost:8080/v3/rtp/polygon"
+    );
+}
+
+#[test]
+fn evm_collections_response() {
+    let body = r#"{
+        "collections": [{
+            "id": "0xbc4ca0eda7647a8ab7c2061c2e118a18a936f13d",
+            "name": "Bored Ape Yacht Club",
+            "tokenCount": "9998",
+            "floorAsk": {
+                "id": "0x1",
+                "price": {
+                    "currency": { "contract": "0x0000000000000000000000000000000000000000", "symbol": "ETH", "decimals": 18 },
+                    "amount": { "raw": "12500000000000000000", "decimal": 12.5, "usd": 40000.0, "native": 12.5 }
+                }
+            },
+            "volume": { "1day": 120.5, "allTime": 1000000.0 }
+        }],
+        "continuation": "MTIzNDU="
+    }"#;
+
+    let response: EvmCollectionsResponse = serde_json::from_str(body).unwrap();
+    assert_eq!(response.continuation.as_deref(), Some("MTIzNDU="));
+    let collection = &response.collections[0];
+    let floor = collection
+        .floor_ask
+        .as_ref()
+        .unwrap()
+        .price
+        .as_ref()
+        .unwrap();
+    assert_eq!(floor.amount.decimal, 12.5);
+    assert_eq!(collection.volume.as_ref().unwrap().one_day, Some(120.5));
+}
@@ -0,0 +1,111 @@
+use magiceden_rs::{
+    export::{self, Record},
+    types::{Collection, Listing},
+};
+
+mod common;
+
+const RARITY: &str = r#"{
+    "moonrank": { "crawl": { "complete": true, "id": "1" }, "absolute_rarity": 5, "rank": 100 },
+    "howrare": { "rank": 7 }
+}"#;
+
+fn listings() -> Vec<Listing> {
+    vec![
+        common::listing_of("mint1", "seller1", 1.5, RARITY),
+        common::listing_of("mint2", "seller2", 2.0, "{}"),
+    ]
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn export_listings_csv() {
+    let mut out = vec![];
+    export::write_csv(&mut out, &listings()).unwrap();
+    let csv = String::from_utf8(out).unwrap();
+    let mut lines = csv.lines();
+
+    assert_eq!(
+        lines.next().unwrap(),
+        "pda_address,auction_house,token_address,token_mint,seller,seller_referral,token_size,price,expiry,\
+         rarity_howrare_rank,rarity_moonrank_rank,rarity_moonrank_absolute_rarity,rarity_moonrank_crawl_complete,\
+         rarity_merarity_rank,rarity_merarity_score,rarity_merarity_total_supply,extra_img"
+    );
+    assert_eq!(
+        lines.next().unwrap(),
+        "pda-mint1,E8cU1WiRWjanGxmn96ewBgk9vPTcL6AEZ1t6F6fkgUWe,ata-mint1,mint1,seller1,,1,1.5,-1,7,100,5,true,,,,"
+    );
+    assert_eq!(
+        lines.next().unwrap(),
+        "pda-mint2,E8cU1WiRWjanGxmn96ewBgk9vPTcL6AEZ1t6F6fkgUWe,ata-mint2,mint2,seller2,,1,2,-1,,,,,,,,"
+    );
+    assert!(lines.next().is_none());
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn export_pools_csv() {
+    let pools = vec![common::pool("pool1", 1.25)];
+    let mut out = vec![];
+    export::write_csv(&mut out, &pools).unwrap();
+    let csv = String::from_utf8(out).unwrap();
+    let rows: Vec<Vec<&str>> = csv.lines().map(|line| line.split(',').collect()).collect();
+    let column = |name: &str| rows[0].iter().position(|column| *column == name).unwrap();
+    assert_eq!(rows[0].len(), magiceden_rs::types::MmmPool::COLUMNS.len());
+    assert_eq!(rows[1][column("pool_type")], "buy_sided");
+    assert_eq!(rows[1][column("curve_type")], "linear");
+    assert_eq!(rows[1][column("spot_price")], "1.25");
+}
+
+#[test]
+fn export_collections_cells() {
+    let collection: Collection = serde_json::from_value(serde_json::json!({
+        "symbol": "okay_bears",
+        "name": "Okay Bears",
+        "description": "",
+        "image": "",
+        "categories": ["pfp", "art"],
+    }))
+    .unwrap();
+    let cells = collection.cells();
+    assert_eq!(cells.len(), Collection::COLUMNS.len());
+    assert_eq!(cells[9], export::Cell::Text(r#"["pfp","art"]"#.to_string()));
+}
+
+#[test]
+fn export_ndjson_round_trip() {
+    let listings = listings();
+    let mut out = vec![];
+    export::write_ndjson(&mut out, &listings).unwrap();
+    let ndjson = String::from_utf8(out).unwrap();
+
+    let parsed: Vec<Listing> = ndjson
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(parsed, listings);
+}
+
+#[cfg(feature = "parquet")]
+#[test]
+fn export_listings_parquet() {
+    use parquet::file::reader::{FileReader, SerializedFileReader};
+
+    let mut out = vec![];
+    export::write_parquet(&mut out, &listings()).unwrap();
+
+    let reader = SerializedFileReader::new(bytes::Bytes::from(out)).unwrap();
+    let metadata = reader.metadata();
+    assert_eq!(metadata.file_metadata().num_rows(), 2);
+    let schema = metadata.file_metadata().schema_descr();
+    assert_eq!(schema.num_columns(), Listing::COLUMNS.len());
+    assert_eq!(schema.column(10).name(), "rarity_moonrank_rank");
+
+    let rows: Vec<String> = reader
+        .get_row_iter(None)
+        .unwrap()
+        .map(|row| row.unwrap().to_string())
+        .collect();
+    assert!(rows[0].contains("rarity_moonrank_rank: 100"));
+    assert!(rows[1].contains("rarity_moonrank_rank: null"));
+}
@@ -0,0 +1,121 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use magiceden_rs::{error::MagicedenError, ApiKeys, Client, KeySelection};
+
+mod common;
+
+const STATS: &str = r#"{"symbol":"okay_bears"}"#;
+const NO_QUOTA: &str =
+    r#"{"error":{"message":"You exceeded your current quota","type":"insufficient_quota"}}"#;
+
+/// Serve stats, answering `insufficient_quota` to the `exhausted` keys, and record the keys used
+fn serve(exhausted: &'static [&'static str]) -> (String, Arc<Mutex<Vec<String>>>) {
+    let keys = Arc::new(Mutex::new(vec![]));
+    let base = common::serve_raw({
+        let keys = keys.clone();
+        move |request| {
+            let key = request
+                .header("authorization")
+                .unwrap_or_default()
+                .trim_start_matches("Bearer ")
+                .to_string();
+            keys.lock().unwrap().push(key.clone());
+            if exhausted.contains(&key.as_str()) {
+                (429, "application/json", NO_QUOTA.to_string())
+            } else {
+                (200, "application/json", STATS.to_string())
+            }
+        }
+    });
+    (base, keys)
+}
+
+async fn stats(client: &Client, times: usize) -> Result<(), MagicedenError> {
+    for _ in 0..times {
+        client.collections().stats("okay_bears").await?;
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn round_robin_rotates_keys() {
+    let (base, used) = serve(&[]);
+    let client = Client::new()
+        .with_api_base(base)
+        .with_api_keys(ApiKeys::new(["a", "b", "c"]).unwrap());
+
+    stats(&client, 4).await.unwrap();
+    assert_eq!(*used.lock().unwrap(), ["a", "b", "c", "a"]);
+    let requests: Vec<_> = client
+        .api_keys()
+        .usage()
+        .iter()
+        .map(|usage| usage.requests)
+        .collect();
+    assert_eq!(requests, [2, 1, 1]);
+    assert_eq!(client.api_key().unwrap().expose_secret(), "a");
+}
+
+#[tokio::test]
+async fn least_used_balances_clones_sharing_the_pool() {
+    let (base, used) = serve(&[]);
+    let client = Client::new().with_api_base(base).with_api_keys(
+        ApiKeys::new(["a", "b"])
+            .unwrap()
+            .with_selection(KeySelection::LeastUsed),
+    );
+
+    stats(&client, 1).await.unwrap();
+    stats(&client.clone(), 3).await.unwrap();
+    assert_eq!(*used.lock().unwrap(), ["a", "b", "a", "b"]);
+}
+
+#[tokio::test]
+async fn exceeded_quota_fails_over_to_another_key() {
+    let (base, used) = serve(&["a"]);
+    let client = Client::new()
+        .with_api_base(base)
+        .with_api_keys(ApiKeys::new(["a", "b"]).unwrap());
+
+    stats(&client, 3).await.unwrap();
+    assert_eq!(*used.lock().unwrap(), ["a", "b", "b", "b"]);
+    let usage = client.api_keys().usage();
+    assert!(usage[0].exhausted);
+    assert_eq!(usage[0].rate_limited, 0);
+    assert!(!usage[1].exhausted);
+}
+
+#[tokio::test]
+async fn exceeded_quota_of_every_key_is_returned() {
+    let (base, used) = serve(&["a", "b"]);
+    let client = Client::new()
+        .with_api_base(base)
+        .with_api_keys(ApiKeys::new(["a", "b"]).unwrap());
+
+    match stats(&client, 1).await {
+        Err(MagicedenError::ApiError(e)) => {
+            assert_eq!(e.r#type.as_deref(), Some("insufficient_quota"))
+        }
+        e => panic!("unexpected result {e:?}"),
+    }
+    assert_eq!(*used.lock().unwrap(), ["a", "b"]);
+}
+
+#[tokio::test]
+async fn rate_limit_spaces_requests_of_a_key() {
+    let (base, _) = serve(&[]);
+    let client = Client::new().with_api_base(base).with_api_keys(
+        ApiKeys::new(["a", "b"])
+            .unwrap()
+            .with_rate_limit(1, Duration::from_millis(100)),
+    );
+
+    let start = Instant::now();
+    stats(&client, 4).await.unwrap();
+    let elapsed = start.elapsed();
+    assert!(elapsed >= Duration::from_millis(100), "{elapsed:?}");
+    assert!(elapsed < Duration::from_millis(300), "{elapsed:?}");
+}
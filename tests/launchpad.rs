@@ -0,0 +1,32 @@
+use magiceden_rs::types::LaunchpadCollectionsResponse;
+
+mod common;
+
+#[test]
+fn launchpad_phase_schedule() {
+    let body = r#"[{
+        "symbol": "okay_bears",
+        "name": "Okay Bears",
+        "price": 1.5,
+        "size": 10000,
+        "launchDatetime": "2022-04-26T16:00:00.000Z",
+        "state": {
+            "itemsAvailable": 10000,
+            "itemsRedeemed": 0,
+            "phases": [
+                { "label": "Allowlist", "startTime": 1000, "endTime": 2000, "price": 1.0, "maxPerWallet": 2, "isAllowlist": true },
+                { "label": "Public", "startTime": 2000, "price": 1.5 }
+            ]
+        }
+    }]"#;
+
+    let response: LaunchpadCollectionsResponse = serde_json::from_str(body).unwrap();
+    let collection = &response[0];
+
+    assert!(collection.phase_at(500).is_none());
+    assert_eq!(collection.next_phase(500).unwrap().label, "Allowlist");
+    assert_eq!(collection.phase_at(1500).unwrap().label, "Allowlist");
+    assert_eq!(collection.phase_at(2000).unwrap().label, "Public");
+    assert_eq!(collection.phase_at(1_000_000).unwrap().price, 1.5);
+    assert!(collection.next_phase(2000).is_none());
+}
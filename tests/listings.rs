@@ -0,0 +1,272 @@
+use magiceden_rs::types::{
+    diff_listings, Attribute, AttributeFilter, CollectionListingsRequestArgs, Listing,
+    ListingEvent, ListingKey, ListingTracker, RarityProvider,
+};
+
+mod common;
+
+fn query_string<Q: serde::Serialize>(query: &Q) -> String {
+    let request = reqwest::Client::new()
+        .get("https://api-mainnet.magiceden.dev/v2/collections/okay_bears/listings")
+        .query(query)
+        .build()
+        .unwrap();
+    request.url().query().unwrap_or_default().to_string()
+}
+
+#[test]
+fn listings_attribute_filter_query() {
+    let request = CollectionListingsRequestArgs::default()
+        .limit(20)
+        .attributes(AttributeFilter::any_of([
+            AttributeFilter::all_of([
+                Attribute::new("Fur", "Gold"),
+                Attribute::new("Hat", "Crown"),
+            ]),
+            AttributeFilter::all_of([Attribute::new("Eyes", "Laser")]),
+        ]))
+        .build()
+        .unwrap();
+
+    let attributes = r#"[[{"value":"Gold","traitType":"Fur"},{"value":"Crown","traitType":"Hat"}],[{"value":"Laser","traitType":"Eyes"}]]"#;
+    let expected = reqwest::Url::parse_with_params(
+        "https://localhost",
+        &[("limit", "20"), ("attributes", attributes)],
+    )
+    .unwrap();
+    assert_eq!(query_string(&request), expected.query().unwrap());
+}
+
+#[test]
+fn listings_attribute_filter_composition() {
+    let filter = AttributeFilter::from(Attribute::new("Fur", "Gold"))
+        .or(Attribute::new("Fur", "Brown").into());
+    assert_eq!(
+        filter,
+        AttributeFilter::any_of([
+            AttributeFilter::all_of([Attribute::new("Fur", "Gold")]),
+            AttributeFilter::all_of([Attribute::new("Fur", "Brown")]),
+        ])
+    );
+    assert_eq!(
+        query_string(&[("attributes", &filter)]),
+        "attributes=%5B%5B%7B%22value%22%3A%22Gold%22%2C%22traitType%22%3A%22Fur%22%7D%5D%2C%5B%7B%22value%22%3A%22Brown%22%2C%22traitType%22%3A%22Fur%22%7D%5D%5D"
+    );
+
+    let without_filter = CollectionListingsRequestArgs::default().build().unwrap();
+    assert_eq!(query_string(&without_filter), "");
+}
+
+#[test]
+fn listings_attribute_filter_roundtrip() {
+    let filter = AttributeFilter::all_of([Attribute::new("Fur", "Gold")]);
+    let json = serde_json::to_string(&filter).unwrap();
+    let decoded: AttributeFilter = serde_json::from_str(&json).unwrap();
+    assert_eq!(decoded, filter);
+
+    let decoded: AttributeFilter =
+        serde_json::from_str(r#"[[{"trait_type":"Fur","value":"Gold"}]]"#).unwrap();
+    assert_eq!(decoded, filter);
+}
+
+#[test]
+fn listings_rarity_rank() {
+    let complete = common::listing(
+        10.0,
+        r#"{
+            "howrare": { "rank": 120 },
+            "moonrank": { "crawl": { "complete": true, "id": "1" }, "absolute_rarity": 5, "rank": 100 },
+            "merarity": { "tokenKey": "mint", "score": 1.5, "rank": 80 }
+        }"#,
+    );
+    assert_eq!(complete.best_rank(), Some(100));
+    assert_eq!(complete.rank(RarityProvider::Merarity), Some(80));
+    assert_eq!(
+        complete
+            .rarity
+            .best_rank_by(&[RarityProvider::Merarity, RarityProvider::Moonrank]),
+        Some((RarityProvider::Merarity, 80))
+    );
+    assert_eq!(complete.rarity.percentile(10000), Some(0.01));
+
+    let incomplete_crawl = common::listing(
+        10.0,
+        r#"{
+            "howrare": { "rank": 120 },
+            "moonrank": { "crawl": { "complete": false, "id": "1" }, "absolute_rarity": 5, "rank": 3 }
+        }"#,
+    );
+    assert_eq!(incomplete_crawl.rank(RarityProvider::Moonrank), None);
+    assert_eq!(incomplete_crawl.best_rank(), Some(120));
+
+    let unranked = common::listing(10.0, "{}");
+    assert_eq!(unranked.best_rank(), None);
+    assert_eq!(unranked.rarity.percentile(10000), None);
+}
+
+fn listing_with(token_mint: &str, seller: &str, price: f64, expiry: i64) -> Listing {
+    let mut listing = common::listing_of(token_mint, seller, price, "{}");
+    listing.expiry = expiry;
+    listing
+}
+
+#[test]
+fn listings_diff() {
+    let previous = vec![
+        listing_with("a", "alice", 1.0, -1),
+        listing_with("b", "bob", 2.0, -1),
+        listing_with("c", "carol", 3.0, -1),
+        listing_with("d", "dave", 4.0, -1),
+    ];
+    let current = vec![
+        listing_with("e", "erin", 5.0, -1),
+        listing_with("a", "alice", 1.0, -1),
+        listing_with("b", "bob", 1.5, 1700000000),
+        listing_with("c", "frank", 3.0, -1),
+    ];
+
+    let events = diff_listings(&previous, &current);
+    assert_eq!(
+        events,
+        vec![
+            ListingEvent::Listed {
+                listing: current[0].clone()
+            },
+            ListingEvent::PriceChanged {
+                listing: current[2].clone(),
+                old: 2.0,
+                new: 1.5
+            },
+            ListingEvent::ExpiryChanged {
+                listing: current[2].clone(),
+                old: -1,
+                new: 1700000000
+            },
+            ListingEvent::SellerChanged {
+                listing: current[3].clone(),
+                old: "carol".to_string(),
+                new: "frank".to_string()
+            },
+            ListingEvent::Delisted {
+                listing: previous[3].clone()
+            },
+        ]
+    );
+    assert!(diff_listings(&current, &current).is_empty());
+}
+
+#[test]
+fn listings_tracker_by_pda_address() {
+    let mut tracker = ListingTracker::new(ListingKey::PdaAddress);
+    let first = vec![listing_with("a", "alice", 1.0, -1)];
+    assert_eq!(tracker.update(first.clone()).len(), 1);
+    assert!(tracker.update(first.clone()).is_empty());
+
+    let mut relisted = listing_with("a", "bob", 1.0, -1);
+    relisted.pda_address = "pda-a-bob".to_string();
+    let events = tracker.update(vec![relisted.clone()]);
+    assert_eq!(
+        events,
+        vec![
+            ListingEvent::Listed {
+                listing: relisted.clone()
+            },
+            ListingEvent::Delisted {
+                listing: first[0].clone()
+            },
+        ]
+    );
+    assert_eq!(tracker.get("pda-a-bob"), Some(&relisted));
+}
+
+#[test]
+fn listings_tracker_keeps_the_first_of_duplicate_keys() {
+    let mut tracker = ListingTracker::new(ListingKey::TokenMint);
+    let snapshot = vec![
+        listing_with("a", "alice", 1.0, -1),
+        listing_with("a", "bob", 2.0, -1),
+        listing_with("b", "carol", 3.0, -1),
+    ];
+    assert_eq!(tracker.update(snapshot.clone()).len(), 2);
+    assert_eq!(
+        tracker.listings(),
+        &[snapshot[0].clone(), snapshot[2].clone()]
+    );
+    assert_eq!(tracker.get("a"), Some(&snapshot[0]));
+
+    assert!(tracker.update(snapshot.clone()).is_empty());
+    assert!(tracker.update(snapshot).is_empty());
+}
+
+#[test]
+fn listings_rarity_adjusted_price() {
+    let listings: Vec<Listing> = serde_json::from_str(
+        r#"[
+            {
+                "pdaAddress": "8bbCGqJZQUjT2oXBLT1rdP9QNUPw3UqGrvXGxJvUbC4X",
+                "auctionHouse": "E8cU1WiRWjanGxmn96ewBgk9vPTcL6AEZ1t6F6fkgUWe",
+                "tokenAddress": "3xTrPp8AjsoLoGNMhsy5Yyrkg8WbnE2D3Vj1Lh2GqCzX",
+                "tokenMint": "GrailTokenMint111111111111111111111111111111",
+                "seller": "5kVvHtKbTwmqGyThQyeyDCVw4tXp6DFrm8XyQX1cVRDn",
+                "tokenSize": 1,
+                "price": 25.0,
+                "rarity": { "howrare": { "rank": 1 } },
+                "extra": {},
+                "expiry": -1
+            },
+            {
+                "pdaAddress": "2kcH4ZgVYpA3PdE8s7V9RkXJrGfWmMC6GkQRMpQf9mR3",
+                "auctionHouse": "E8cU1WiRWjanGxmn96ewBgk9vPTcL6AEZ1t6F6fkgUWe",
+                "tokenAddress": "6VpTm2gZ9QhPLkvuB5Ch8cRZyXrqFQdqu2ZPv8VEi8hK",
+                "tokenMint": "RareTokenMint1111111111111111111111111111111",
+                "seller": "5kVvHtKbTwmqGyThQyeyDCVw4tXp6DFrm8XyQX1cVRDn",
+                "tokenSize": 1,
+                "price": 9.0,
+                "rarity": {
+                    "moonrank": { "crawl": { "complete": true, "id": "1" }, "absolute_rarity": 5, "rank": 100 }
+                },
+                "extra": {},
+                "expiry": -1
+            },
+            {
+                "pdaAddress": "Hk8tQnXfbs9n5RDRtFcDq1WnCkrNq8Bj2ZRt1wYRcSbG",
+                "auctionHouse": "E8cU1WiRWjanGxmn96ewBgk9vPTcL6AEZ1t6F6fkgUWe",
+                "tokenAddress": "AcmD7GtyDqNHQ5B4Xy7wdqRZFcYX1vQS3Bm7jFqHZy2R",
+                "tokenMint": "FloorTokenMint11111111111111111111111111111",
+                "seller": "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM",
+                "tokenSize": 1,
+                "price": 4.0,
+                "rarity": { "merarity": { "tokenKey": "mint", "score": 0.2, "rank": 10000 } },
+                "extra": {},
+                "expiry": -1
+            }
+        ]"#,
+    )
+    .unwrap();
+    let adjusted: Vec<_> = listings
+        .iter()
+        .map(|listing| {
+            listing
+                .rarity_adjusted_price(10000, &RarityProvider::DEFAULT_ORDER)
+                .unwrap()
+        })
+        .collect();
+    // Rarity scores of 5, 3 and 1
+    assert_eq!(adjusted.len(), 3);
+    for (adjusted, expected) in adjusted.iter().zip([5.0, 3.0, 4.0]) {
+        assert!(
+            (adjusted - expected).abs() < 1e-9,
+            "{adjusted} != {expected}"
+        );
+    }
+
+    let unranked = common::listing(10.0, "{}");
+    assert_eq!(
+        unranked.rarity_adjusted_price(10000, &RarityProvider::DEFAULT_ORDER),
+        None
+    );
+    assert_eq!(
+        listings[0].rarity_adjusted_price(0, &RarityProvider::DEFAULT_ORDER),
+        None
+    );
+}
@@ -0,0 +1,128 @@
+#![cfg(feature = "metrics")]
+
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use magiceden_rs::{
+    metrics,
+    types::{EvmChain, EvmTokensRequest},
+    Client,
+};
+use metrics_util::debugging::{DebugValue, DebuggingRecorder, Snapshotter};
+
+mod common;
+
+fn counter(snapshotter: &Snapshotter, name: &str, labels: &[(&str, &str)]) -> u64 {
+    snapshotter
+        .snapshot()
+        .into_vec()
+        .into_iter()
+        .filter(|(key, ..)| {
+            let key = key.key();
+            key.name() == name
+                && labels.iter().all(|(label, value)| {
+                    key.labels()
+                        .any(|l| l.key() == *label && l.value() == *value)
+                })
+        })
+        .map(|(.., value)| match value {
+            DebugValue::Counter(value) => value,
+            _ => 0,
+        })
+        .sum()
+}
+
+#[tokio::test]
+async fn requests_are_counted_per_endpoint() {
+    let recorder = DebuggingRecorder::new();
+    let snapshotter = recorder.snapshotter();
+    recorder.install().unwrap();
+
+    let requests = Arc::new(AtomicUsize::new(0));
+    let base = common::serve({
+        let requests = requests.clone();
+        move |path| {
+            if path.contains("holder_stats") {
+                return (200, "not json".to_string());
+            }
+            if path.contains("/tokens/v6") {
+                return (200, r#"{"tokens":[]}"#.to_string());
+            }
+            match requests.fetch_add(1, Ordering::SeqCst) {
+                0 => (
+                    429,
+                    r#"{"error":{"message":"slow down","type":null}}"#.to_string(),
+                ),
+                _ => (200, r#"{"symbol":"okay_bears"}"#.to_string()),
+            }
+        }
+    });
+
+    let backoff = backoff::ExponentialBackoff {
+        initial_interval: Duration::from_millis(10),
+        ..Default::default()
+    };
+    let client = Client::new()
+        .with_api_base(&base)
+        .with_evm_api_base(base)
+        .with_backoff(backoff);
+    client.collections().stats("okay_bears").await.unwrap();
+    client.collections().stats("degods").await.unwrap();
+    assert!(client
+        .collections()
+        .holder_stats("okay_bears")
+        .await
+        .is_err());
+    for chain in [EvmChain::Ethereum, EvmChain::Polygon, EvmChain::Polygon] {
+        client
+            .evm(chain)
+            .tokens(EvmTokensRequest::default())
+            .await
+            .unwrap();
+    }
+
+    // Snapshots drain histograms, so look at latencies before counting
+    let durations = snapshotter
+        .snapshot()
+        .into_vec()
+        .into_iter()
+        .find_map(|(key, .., value)| match value {
+            DebugValue::Histogram(values) if key.key().name() == metrics::REQUEST_DURATION => {
+                Some(values)
+            }
+            _ => None,
+        })
+        .unwrap();
+    assert!(!durations.is_empty());
+
+    let stats = [("endpoint", "/collections/{symbol}/stats")];
+    let ok = [
+        ("endpoint", "/collections/{symbol}/stats"),
+        ("method", "GET"),
+        ("status", "200"),
+    ];
+    let limited = [
+        ("endpoint", "/collections/{symbol}/stats"),
+        ("status", "429"),
+    ];
+    assert_eq!(counter(&snapshotter, metrics::REQUESTS, &ok), 2);
+    assert_eq!(counter(&snapshotter, metrics::REQUESTS, &limited), 1);
+    assert_eq!(counter(&snapshotter, metrics::RETRIES, &stats), 1);
+    assert_eq!(counter(&snapshotter, metrics::RATE_LIMITED, &stats), 1);
+
+    let holders = [("endpoint", "/collections/{symbol}/holder_stats")];
+    assert_eq!(
+        counter(&snapshotter, metrics::DESERIALIZATION_FAILURES, &holders),
+        1
+    );
+
+    let polygon = [("endpoint", "/polygon/tokens/v6"), ("status", "200")];
+    assert_eq!(counter(&snapshotter, metrics::REQUESTS, &polygon), 2);
+    let ethereum = [("endpoint", "/ethereum/tokens/v6"), ("status", "200")];
+    assert_eq!(counter(&snapshotter, metrics::REQUESTS, &ethereum), 1);
+}
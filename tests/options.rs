@@ -0,0 +1,133 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+use magiceden_rs::{
+    error::MagicedenError,
+    types::{RequestOptions, RequestOptionsArgs},
+    Client,
+};
+
+mod common;
+
+const STATS: &str = r#"{"symbol":"okay_bears"}"#;
+const RATE_LIMITED: &str = r#"{"error":{"message":"slow down","type":null}}"#;
+
+fn client(base: String) -> Client {
+    let backoff = backoff::ExponentialBackoff {
+        initial_interval: Duration::from_millis(10),
+        ..Default::default()
+    };
+    Client::new().with_api_base(base).with_backoff(backoff)
+}
+
+#[tokio::test]
+async fn options_add_headers_and_query() {
+    let seen = Arc::new(Mutex::new(vec![]));
+    let base = common::serve_raw({
+        let seen = seen.clone();
+        move |request| {
+            seen.lock().unwrap().push((
+                request.path.clone(),
+                request.header("x-trace").map(str::to_string),
+                request.header("idempotency-key").map(str::to_string),
+                request.header("cache-control").map(str::to_string),
+            ));
+            (200, "application/json", STATS.to_string())
+        }
+    });
+
+    let options = RequestOptionsArgs::default()
+        .header("X-Trace", "abc")
+        .query_param("source", "bot")
+        .idempotency_key("buy-1")
+        .bypass_cache(true)
+        .build()
+        .unwrap();
+    let client = client(base);
+    let stats = client
+        .collections()
+        .stats_with_options("okay_bears", &options)
+        .await
+        .unwrap();
+    assert_eq!(stats.symbol, "okay_bears");
+    client.collections().stats("okay_bears").await.unwrap();
+
+    let seen = seen.lock().unwrap();
+    assert_eq!(
+        seen[0],
+        (
+            "/collections/okay_bears/stats?source=bot".to_string(),
+            Some("abc".to_string()),
+            Some("buy-1".to_string()),
+            Some("no-cache".to_string()),
+        )
+    );
+    assert_eq!(
+        seen[1],
+        (
+            "/collections/okay_bears/stats".to_string(),
+            None,
+            None,
+            None
+        )
+    );
+}
+
+#[tokio::test]
+async fn max_retries_bounds_rate_limited_retries() {
+    let requests = Arc::new(AtomicUsize::new(0));
+    let base = common::serve({
+        let requests = requests.clone();
+        move |_| {
+            requests.fetch_add(1, Ordering::SeqCst);
+            (429, RATE_LIMITED.to_string())
+        }
+    });
+    let client = client(base);
+
+    let error = client
+        .collections()
+        .stats_with_options("okay_bears", &RequestOptions::no_retry())
+        .await
+        .unwrap_err();
+    assert!(matches!(error, MagicedenError::ApiError(_)), "{error}");
+    assert_eq!(requests.swap(0, Ordering::SeqCst), 1);
+
+    let options = RequestOptionsArgs::default()
+        .max_retries(2u32)
+        .build()
+        .unwrap();
+    client
+        .collections()
+        .stats_with_options("okay_bears", &options)
+        .await
+        .unwrap_err();
+    assert_eq!(requests.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn timeout_fails_slow_requests() {
+    let base = common::serve(|_| {
+        std::thread::sleep(Duration::from_millis(500));
+        (200, STATS.to_string())
+    });
+
+    let options = RequestOptionsArgs::default()
+        .timeout(Duration::from_millis(50))
+        .build()
+        .unwrap();
+    let error = client(base)
+        .collections()
+        .stats_with_options("okay_bears", &options)
+        .await
+        .unwrap_err();
+    match error {
+        MagicedenError::Reqwest(e) => assert!(e.is_timeout(), "{e}"),
+        e => panic!("unexpected error {e}"),
+    }
+}
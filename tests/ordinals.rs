@@ -0,0 +1,46 @@
+use magiceden_rs::{
+    types::{OrdinalsTokensRequestArgs, OrdinalsTokensResponse},
+    Client, ORDINALS_API_BASE,
+};
+
+mod common;
+
+#[test]
+fn ordinals_api_base() {
+    let client = common::setup_client();
+    assert_eq!(client.ordinals_api_base(), ORDINALS_API_BASE);
+
+    let client = Client::new().with_ordinals_api_base("http://localhost:8080");
+    assert_eq!(client.ordinals_api_base(), "http://localhost:8080");
+}
+
+#[test]
+fn ordinals_tokens_response() {
+    let body = r#"{
+        "tokens": [{
+            "id": "6fb976ab49dcec017f1e201e84395983204ae1a7c2abf7ced0a85d692e442799i0",
+            "inscriptionNumber": 1,
+            "contentURI": "https://ord-mirror.magiceden.dev/content/6fb976ab49dcec017f1e201e84395983204ae1a7c2abf7ced0a85d692e442799i0",
+            "contentType": "image/png",
+            "satRarity": "common",
+            "owner": "bc1pxvywh5kq8ndpmffr9wrxz7d4p3l3gn4ngmu59x3aktz3f6sqgefq7jymqf",
+            "collectionSymbol": "ordinal-punks",
+            "listed": true,
+            "listedPrice": 150000000,
+            "meta": { "name": "Punk #1", "attributes": [{ "trait_type": "Type", "value": "Alien" }] }
+        }]
+    }"#;
+
+    let response: OrdinalsTokensResponse = serde_json::from_str(body).unwrap();
+    let token = &response.tokens[0];
+    assert_eq!(token.inscription_number, Some(1));
+    assert_eq!(token.listed_price, Some(150000000));
+    assert_eq!(token.collection_symbol.as_deref(), Some("ordinal-punks"));
+
+    let request = OrdinalsTokensRequestArgs::default()
+        .collection_symbol("ordinal-punks")
+        .limit(20)
+        .build()
+        .unwrap();
+    assert_eq!(request.owner_address, None);
+}
@@ -0,0 +1,255 @@
+use std::sync::{Arc, Mutex};
+
+use magiceden_rs::{
+    types::{
+        Attribute, CollectionAttributesResponse, InventoryItem, MarketPrices, PricingRule,
+        RepriceAction, RepriceRequestArgs,
+    },
+    Client,
+};
+
+mod common;
+
+const SELLER: &str = "seller";
+
+fn items() -> Vec<InventoryItem> {
+    vec![
+        InventoryItem::new("a", "ata-a").with_attributes(vec![Attribute::new("Fur", "Gold")]),
+        InventoryItem::new("b", "ata-b").with_attributes(vec![
+            Attribute::new("Fur", "Brown"),
+            Attribute::new("Hat", "Crown"),
+        ]),
+        InventoryItem::new("c", "ata-c").with_attributes(vec![Attribute::new("Fur", "Blue")]),
+    ]
+}
+
+fn actions(rule: PricingRule, market: &MarketPrices) -> Vec<RepriceAction> {
+    let request = RepriceRequestArgs::default()
+        .seller(SELLER)
+        .auction_house_address("E8cU1WiRWjanGxmn96ewBgk9vPTcL6AEZ1t6F6fkgUWe")
+        .collection_symbol("okay_bears")
+        .items(items())
+        .rule(rule)
+        .min_price(1.0)
+        .build()
+        .unwrap();
+    let listings = vec![
+        common::listing_of("a", SELLER, 10.0, "{}"),
+        common::listing_of("b", SELLER, 12.0, "{}"),
+        common::listing_of("c", "someone-else", 11.0, "{}"),
+    ];
+
+    request
+        .diff(&listings, market)
+        .changes
+        .into_iter()
+        .map(|change| change.action)
+        .collect()
+}
+
+#[test]
+fn reprice_below_floor() {
+    let market = MarketPrices {
+        floor_price: Some(11.0),
+        attributes: None,
+    };
+
+    assert_eq!(
+        actions(PricingRule::BelowFloor { percent: 10.0 }, &market),
+        vec![
+            RepriceAction::ChangePrice {
+                old: 10.0,
+                new: 9.9
+            },
+            RepriceAction::ChangePrice {
+                old: 12.0,
+                new: 9.9
+            },
+            RepriceAction::List { price: 9.9 },
+        ]
+    );
+}
+
+#[test]
+fn reprice_fixed_and_delist() {
+    let market = MarketPrices::default();
+
+    assert_eq!(
+        actions(PricingRule::Fixed { price: 10.0 }, &market),
+        vec![
+            RepriceAction::Keep { price: Some(10.0) },
+            RepriceAction::ChangePrice {
+                old: 12.0,
+                new: 10.0
+            },
+            RepriceAction::List { price: 10.0 },
+        ]
+    );
+    assert_eq!(
+        actions(PricingRule::Delist, &market),
+        vec![
+            RepriceAction::Cancel { price: 10.0 },
+            RepriceAction::Cancel { price: 12.0 },
+            RepriceAction::Keep { price: None },
+        ]
+    );
+    assert!(matches!(
+        actions(PricingRule::Fixed { price: 0.5 }, &market)[0],
+        RepriceAction::Skip { .. }
+    ));
+}
+
+#[test]
+fn reprice_below_trait_floor() {
+    let attributes: CollectionAttributesResponse = serde_json::from_str(
+        r#"{
+            "results": {
+                "symbol": "okay_bears",
+                "availableAttributes": [
+                    { "count": 12, "floor": 25000000000, "attribute": { "trait_type": "Fur", "value": "Gold" } },
+                    { "count": 140, "floor": 12000000000, "attribute": { "trait_type": "Fur", "value": "Brown" } },
+                    { "count": 3, "floor": 30000000000, "attribute": { "trait_type": "Hat", "value": "Crown" } }
+                ]
+            }
+        }"#,
+    )
+    .unwrap();
+    let market = MarketPrices {
+        floor_price: None,
+        attributes: Some(attributes.results),
+    };
+
+    let actions = actions(PricingRule::BelowTraitFloor { percent: 0.0 }, &market);
+    assert_eq!(
+        actions[0],
+        RepriceAction::ChangePrice {
+            old: 10.0,
+            new: 25.0
+        }
+    );
+    assert_eq!(
+        actions[1],
+        RepriceAction::ChangePrice {
+            old: 12.0,
+            new: 30.0
+        }
+    );
+    assert!(matches!(actions[2], RepriceAction::Skip { .. }));
+}
+
+fn listing_json(token_mint: &str, seller: &str, price: f64) -> String {
+    format!(
+        r#"{{"pdaAddress":"pda-{token_mint}","auctionHouse":"house-{token_mint}","tokenAddress":"ata-{token_mint}","tokenMint":"{token_mint}","seller":"{seller}","tokenSize":1,"price":{price},"rarity":{{}},"extra":{{}},"expiry":-1}}"#
+    )
+}
+
+/// Serve the listings of the inventory and a 11 SOL floor, answering instructions with the index
+/// of the instruction endpoint in their transaction, and record the instruction paths
+fn serve() -> (String, Arc<Mutex<Vec<String>>>) {
+    let instructions = Arc::new(Mutex::new(vec![]));
+    let base = common::serve({
+        let instructions = instructions.clone();
+        move |path| {
+            let body = match path.split('?').next().unwrap() {
+                "/collections/okay_bears/stats" => {
+                    r#"{"symbol":"okay_bears","floorPrice":11000000000}"#.to_string()
+                }
+                "/tokens/a/listings" => format!("[{}]", listing_json("a", SELLER, 10.0)),
+                "/tokens/b/listings" => format!(
+                    "[{},{}]",
+                    listing_json("b", "someone-else", 8.0),
+                    listing_json("b", SELLER, 9.9)
+                ),
+                "/tokens/c/listings" => "[]".to_string(),
+                endpoint => {
+                    let kind = ["sell", "sell_change_price", "sell_cancel"]
+                        .iter()
+                        .position(|kind| endpoint == format!("/instructions/{kind}"))
+                        .unwrap_or_else(|| panic!("unexpected request {path}"));
+                    instructions.lock().unwrap().push(path.to_string());
+                    format!(
+                        r#"{{"tx":{{"type":"Buffer","data":[{kind}]}},"txSigned":{{"type":"Buffer","data":[{kind}]}}}}"#
+                    )
+                }
+            };
+            (200, body)
+        }
+    });
+    (base, instructions)
+}
+
+/// Instruction request of `mint`, instructions are requested concurrently so paths come in any order
+fn path_of<'p>(paths: &'p [String], mint: &str) -> &'p str {
+    paths
+        .iter()
+        .find(|path| path.contains(&format!("tokenMint={mint}&")))
+        .unwrap_or_else(|| panic!("no instruction for {mint} in {paths:?}"))
+}
+
+#[tokio::test]
+async fn reprice_plan_and_instructions() {
+    let (base, paths) = serve();
+    let client = Client::new().with_api_base(base);
+    let mut request = RepriceRequestArgs::default()
+        .seller(SELLER)
+        .auction_house_address("new-house")
+        .collection_symbol("okay_bears")
+        .items(items())
+        .rule(PricingRule::BelowFloor { percent: 10.0 })
+        .build()
+        .unwrap();
+
+    let plan = client.reprice().plan(&request).await.unwrap();
+    let actions: Vec<_> = plan.changes.iter().map(|c| c.action.clone()).collect();
+    assert_eq!(
+        actions,
+        vec![
+            RepriceAction::ChangePrice {
+                old: 10.0,
+                new: 9.9
+            },
+            RepriceAction::Keep { price: Some(9.9) },
+            RepriceAction::List { price: 9.9 },
+        ]
+    );
+    assert_eq!(plan.changes[1].listing.as_ref().unwrap().seller, SELLER);
+
+    let instructions = client
+        .reprice()
+        .instructions(&request, &plan)
+        .await
+        .unwrap();
+    let kinds: Vec<(&str, u8)> = instructions
+        .iter()
+        .map(|i| (i.change.item.token_mint.as_str(), i.instruction.tx.data[0]))
+        .collect();
+    assert_eq!(kinds, vec![("a", 1), ("c", 0)]);
+    {
+        let paths = paths.lock().unwrap();
+        assert_eq!(paths.len(), 2);
+        // The listing keeps its auction house, new listings use the one of the request
+        let a = path_of(&paths, "a");
+        assert!(a.contains("auctionHouseAddress=house-a&"), "{a}");
+        assert!(a.contains("price=10.0&newPrice=9.9"), "{a}");
+        let c = path_of(&paths, "c");
+        assert!(c.contains("auctionHouseAddress=new-house&"), "{c}");
+        assert!(c.contains("tokenAccount=ata-c&price=9.9"), "{c}");
+    }
+
+    request.rule = Some(PricingRule::Delist);
+    let plan = client.reprice().plan(&request).await.unwrap();
+    let instructions = client
+        .reprice()
+        .instructions(&request, &plan)
+        .await
+        .unwrap();
+    let kinds: Vec<(&str, u8)> = instructions
+        .iter()
+        .map(|i| (i.change.item.token_mint.as_str(), i.instruction.tx.data[0]))
+        .collect();
+    assert_eq!(kinds, vec![("a", 2), ("b", 2)]);
+    let paths = paths.lock().unwrap();
+    assert_eq!(paths.len(), 4);
+    let b = path_of(&paths[2..], "b");
+    assert!(b.contains("tokenAccount=ata-b&price=9.9"), "{b}");
+}
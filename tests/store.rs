@@ -0,0 +1,138 @@
+#![cfg(feature = "store")]
+
+use magiceden_rs::{
+    store::Store,
+    types::{CollectionActivitiy, CollectionStatsResponse},
+};
+
+mod common;
+
+fn activity(signature: &str, kind: &str, block_time: i64, price: f64) -> CollectionActivitiy {
+    serde_json::from_value(serde_json::json!({
+        "signature": signature,
+        "type": kind,
+        "source": "magiceden_v2",
+        "slot": block_time,
+        "blockTime": block_time,
+        "buyerReferral": "",
+        "price": price,
+    }))
+    .unwrap()
+}
+
+fn stats(floor_price: f64, listed_count: i64) -> CollectionStatsResponse {
+    serde_json::from_value(serde_json::json!({
+        "symbol": "okay_bears",
+        "floorPrice": floor_price,
+        "listedCount": listed_count,
+    }))
+    .unwrap()
+}
+
+#[test]
+fn store_upserts_by_key() {
+    let mut store = Store::open_in_memory().unwrap();
+
+    let activities = [
+        activity("sig1", "buyNow", 100, 1.0),
+        activity("sig2", "list", 110, 2.0),
+    ];
+    store.save_activities("okay_bears", &activities).unwrap();
+    store
+        .save_activities("okay_bears", &[activity("sig1", "buyNow", 100, 1.5)])
+        .unwrap();
+    let saved = store.activities("okay_bears", 0).unwrap();
+    assert_eq!(saved.len(), 2);
+    assert_eq!(saved[0].price, 1.5);
+
+    let listing = common::listing_of("mint1", "seller1", 2.0, "{}");
+    store.save_listings("okay_bears", &[listing], 100).unwrap();
+    let repriced = common::listing_of("mint1", "seller1", 1.8, "{}");
+    store
+        .save_listings("okay_bears", std::slice::from_ref(&repriced), 200)
+        .unwrap();
+    assert_eq!(store.listings("okay_bears", 150).unwrap(), vec![repriced]);
+    let first_seen: i64 = store
+        .connection()
+        .query_row("SELECT first_seen FROM listings", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(first_seen, 100);
+
+    store
+        .save_pools(
+            &[common::pool("pool1", 2.0), common::pool("pool2", 1.0)],
+            100,
+        )
+        .unwrap();
+    store
+        .save_pools(&[common::pool("pool1", 0.5)], 200)
+        .unwrap();
+    let pools = store.pools("okay_bears").unwrap();
+    assert_eq!(
+        pools
+            .iter()
+            .map(|pool| pool.pool_key.as_str())
+            .collect::<Vec<_>>(),
+        ["pool1", "pool2"]
+    );
+    assert_eq!(store.pool("pool1").unwrap().unwrap().spot_price, 0.5);
+    assert!(store.pool("pool3").unwrap().is_none());
+}
+
+#[test]
+fn store_history_queries() {
+    let mut store = Store::open_in_memory().unwrap();
+
+    store.save_stats(&stats(1_500_000_000.0, 10), 100).unwrap();
+    store.save_stats(&stats(2_000_000_000.0, 8), 200).unwrap();
+    store.save_stats(&stats(2_500_000_000.0, 5), 300).unwrap();
+    assert_eq!(
+        store.floor_history("okay_bears", 200).unwrap(),
+        vec![(200, 2.0), (300, 2.5)]
+    );
+    assert_eq!(
+        store.listing_count_history("okay_bears", 0).unwrap(),
+        vec![(100, 10), (200, 8), (300, 5)]
+    );
+
+    let activities = [
+        activity("sig1", "buyNow", 100, 1.0),
+        activity("sig2", "buyNow", 150, 2.0),
+        activity("sig3", "list", 160, 5.0),
+        activity("sig4", "buyNow", 260, 4.0),
+    ];
+    store.save_activities("okay_bears", &activities).unwrap();
+    assert_eq!(store.volume("okay_bears", 100, 200).unwrap(), 3.0);
+    assert_eq!(store.volume("other", 0, 1000).unwrap(), 0.0);
+    assert_eq!(
+        store.volume_history("okay_bears", 0, 100).unwrap(),
+        vec![(100, 3.0), (200, 4.0)]
+    );
+    assert!(store.volume_history("okay_bears", 0, 0).is_err());
+}
+
+#[test]
+fn store_schema_version() {
+    let path = std::env::temp_dir().join(format!("magiceden-store-{}.db", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    let store = Store::open(&path).unwrap();
+    let version: usize = store
+        .connection()
+        .query_row("PRAGMA user_version", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(version, Store::schema_version());
+    drop(store);
+
+    // reopening an up to date database does not run the migrations again
+    Store::open(&path).unwrap();
+
+    let store = Store::open(&path).unwrap();
+    store
+        .connection()
+        .pragma_update(None, "user_version", Store::schema_version() + 1)
+        .unwrap();
+    drop(store);
+    assert!(Store::open(&path).is_err());
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,199 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use futures::StreamExt;
+use magiceden_rs::{types::ListingEvent, Client};
+
+mod common;
+
+fn activity(signature: usize) -> String {
+    format!(
+        r#"{{"signature":"sig{signature}","type":"buyNow","source":"magiceden_v2","slot":{signature},"blockTime":{signature},"buyerReferral":"","price":1.0}}"#
+    )
+}
+
+fn client(base: String) -> Client {
+    let backoff = backoff::ExponentialBackoff {
+        initial_interval: Duration::from_millis(10),
+        max_interval: Duration::from_millis(10),
+        ..Default::default()
+    };
+    Client::new()
+        .with_api_base(base)
+        .with_api_key("secret")
+        .unwrap()
+        .with_backoff(backoff)
+}
+
+#[tokio::test]
+async fn stream_activities_reconnect_with_last_event_id() {
+    let last_event_ids = Arc::new(Mutex::new(vec![]));
+    let base = common::serve_raw({
+        let last_event_ids = last_event_ids.clone();
+        move |request| {
+            assert_eq!(request.path, "/collections/okay_bears/activities/stream");
+            assert_eq!(request.header("authorization"), Some("Bearer secret"));
+
+            let last_event_id = request.header("last-event-id").map(str::to_string);
+            last_event_ids.lock().unwrap().push(last_event_id.clone());
+            let body = match last_event_id.as_deref() {
+                None => format!(
+                    "id: 1\ndata: {}\n\nid: 2\ndata: {}\n\n",
+                    activity(1),
+                    activity(2)
+                ),
+                Some("2") => format!(": keep-alive\n\nid: 3\ndata: {}\n\n", activity(3)),
+                Some(_) => String::new(),
+            };
+            (200, "text/event-stream", body)
+        }
+    });
+
+    let client = client(base);
+    let stream = client
+        .collections()
+        .subscribe_activities("okay_bears")
+        .await
+        .unwrap();
+    let signatures: Vec<String> = tokio::time::timeout(
+        Duration::from_secs(5),
+        stream
+            .map(|activity| activity.unwrap().signature)
+            .take(3)
+            .collect(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(signatures, vec!["sig1", "sig2", "sig3"]);
+    let last_event_ids = last_event_ids.lock().unwrap();
+    assert_eq!(last_event_ids[..2], [None, Some("2".to_string())]);
+}
+
+#[tokio::test]
+async fn stream_listings_events() {
+    let listing = common::listing_of("a", "seller", 1.0, "{}");
+    let event = ListingEvent::Listed {
+        listing: listing.clone(),
+    };
+    let base = common::serve_raw({
+        let data = serde_json::to_string(&event).unwrap();
+        move |_| {
+            (
+                200,
+                "text/event-stream",
+                format!("data: {data}\n\ndata: not json\n\n"),
+            )
+        }
+    });
+
+    let client = client(base);
+    let mut stream = client
+        .collections()
+        .subscribe_listings("okay_bears")
+        .await
+        .unwrap();
+    assert_eq!(client.api_keys().usage()[0].requests, 1);
+
+    let first = tokio::time::timeout(Duration::from_secs(5), stream.next())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(first.unwrap(), event);
+    let second = tokio::time::timeout(Duration::from_secs(5), stream.next())
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(second.is_err());
+}
+
+#[tokio::test]
+async fn stream_invalid_status_closes() {
+    let base = common::serve_raw(|_| (404, "application/json", "{}".to_string()));
+
+    let client = client(base);
+    let mut stream = client
+        .collections()
+        .subscribe_listings("okay_bears")
+        .await
+        .unwrap();
+
+    let error = stream.next().await.unwrap();
+    assert!(error.is_err());
+    assert!(stream.next().await.is_none());
+}
+
+#[tokio::test]
+async fn stream_slow_consumer_receives_every_event() {
+    let base = common::serve_raw(|request| {
+        let body = match request.header("last-event-id") {
+            None => (1..=200)
+                .map(|signature| format!("id: {signature}\ndata: {}\n\n", activity(signature)))
+                .collect(),
+            Some(_) => String::new(),
+        };
+        (200, "text/event-stream", body)
+    });
+
+    let client = client(base);
+    let mut stream = client
+        .collections()
+        .subscribe_activities("okay_bears")
+        .await
+        .unwrap();
+
+    // Events wait in the bounded buffer and the connection while the consumer is busy
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    let mut signatures = vec![];
+    while signatures.len() < 200 {
+        let activity = tokio::time::timeout(Duration::from_secs(5), stream.next())
+            .await
+            .unwrap()
+            .unwrap();
+        signatures.push(activity.unwrap().signature);
+    }
+    let expected: Vec<String> = (1..=200)
+        .map(|signature| format!("sig{signature}"))
+        .collect();
+    assert_eq!(signatures, expected);
+}
+
+#[tokio::test]
+async fn stream_dropped_while_idle_closes_the_connection() {
+    use std::io::{BufRead, BufReader, Read, Write};
+
+    // Answers the subscription and then stays silent, reporting when the client hangs up
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let base = format!("http://{}", listener.local_addr().unwrap());
+    let (closed_tx, closed_rx) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        let mut line = String::new();
+        while reader.read_line(&mut line).unwrap() > 2 {
+            line.clear();
+        }
+        stream
+            .write_all(b"HTTP/1.1 200 OK\r\ncontent-type: text/event-stream\r\n\r\n")
+            .unwrap();
+        let mut buf = [0; 64];
+        while !matches!(reader.read(&mut buf), Ok(0) | Err(_)) {}
+        closed_tx.send(()).unwrap();
+    });
+
+    let client = client(base);
+    let stream = client
+        .collections()
+        .subscribe_activities("okay_bears")
+        .await
+        .unwrap();
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    drop(stream);
+
+    tokio::task::spawn_blocking(move || closed_rx.recv_timeout(Duration::from_secs(5)))
+        .await
+        .unwrap()
+        .unwrap();
+}
@@ -0,0 +1,182 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+use magiceden_rs::{
+    types::{SkipReason, SweepRequestArgs},
+    Client,
+};
+
+mod common;
+
+const INSTRUCTION: &str =
+    r#"{"tx":{"type":"Buffer","data":[1]},"txSigned":{"type":"Buffer","data":[2]}}"#;
+
+fn query_param<'p>(path: &'p str, name: &str) -> Option<&'p str> {
+    path.split(['?', '&'])
+        .find_map(|pair| pair.strip_prefix(&format!("{name}=")))
+}
+
+/// Listing `i` of the collection, priced `i` hundredths of SOL
+fn listing_json(i: usize) -> String {
+    format!(
+        r#"{{"pdaAddress":"pda{i}","auctionHouse":"house","tokenAddress":"ata{i}","tokenMint":"mint{i}","seller":"seller","tokenSize":1,"price":{},"rarity":{{}},"extra":{{}},"expiry":-1}}"#,
+        i as f64 / 100.0
+    )
+}
+
+fn ranked(rank: i32) -> String {
+    format!(r#"{{ "howrare": {{ "rank": {rank} }} }}"#)
+}
+
+#[test]
+fn sweep_select_within_budget() {
+    let request = SweepRequestArgs::default()
+        .collection_symbol("okay_bears")
+        .buyer("buyer")
+        .budget(3.0)
+        .build()
+        .unwrap();
+
+    let listings = vec![
+        common::listing_of("c", "seller", 1.2, "{}"),
+        common::listing_of("a", "seller", 0.9, "{}"),
+        common::listing_of("b", "seller", 0.9, "{}"),
+        common::listing_of("d", "buyer", 0.5, "{}"),
+        common::listing_of("e", "seller", 1.3, "{}"),
+    ];
+    let (selected, skipped) = request.select(listings);
+
+    let mints: Vec<&str> = selected.iter().map(|l| l.token_mint.as_str()).collect();
+    assert_eq!(mints, vec!["a", "b", "c"]);
+    assert_eq!(skipped.len(), 2);
+    assert_eq!(skipped[0].listing.token_mint, "d");
+    assert_eq!(skipped[0].reason, SkipReason::OwnListing);
+    assert_eq!(skipped[1].listing.token_mint, "e");
+    assert_eq!(skipped[1].reason, SkipReason::OverBudget);
+}
+
+#[test]
+fn sweep_select_with_rarity_and_item_limit() {
+    let request = SweepRequestArgs::default()
+        .collection_symbol("okay_bears")
+        .buyer("buyer")
+        .budget(100.0)
+        .max_rank(500)
+        .max_items(1_usize)
+        .build()
+        .unwrap();
+
+    let listings = vec![
+        common::listing_of("a", "seller", 1.0, &ranked(900)),
+        common::listing_of("b", "seller", 2.0, "{}"),
+        common::listing_of("c", "seller", 3.0, &ranked(10)),
+        common::listing_of("d", "seller", 4.0, &ranked(20)),
+        common::listing_of("c", "other", 5.0, &ranked(10)),
+    ];
+    let (selected, skipped) = request.select(listings);
+
+    assert_eq!(selected.len(), 1);
+    assert_eq!(selected[0].token_mint, "c");
+    let reasons: Vec<&SkipReason> = skipped.iter().map(|s| &s.reason).collect();
+    assert_eq!(
+        reasons,
+        vec![
+            &SkipReason::RankTooLow { rank: 900 },
+            &SkipReason::Unranked,
+            &SkipReason::MaxItemsReached,
+            &SkipReason::Duplicate,
+        ]
+    );
+}
+
+#[tokio::test]
+async fn sweep_plan_pages_listings_and_requests_instructions() {
+    let listing_pages = Arc::new(Mutex::new(vec![]));
+    let in_flight = Arc::new(AtomicUsize::new(0));
+    let max_in_flight = Arc::new(AtomicUsize::new(0));
+    let base = common::serve({
+        let listing_pages = listing_pages.clone();
+        let in_flight = in_flight.clone();
+        let max_in_flight = max_in_flight.clone();
+        move |path| {
+            if path.starts_with("/collections/okay_bears/listings") {
+                let offset: usize = query_param(path, "offset").unwrap().parse().unwrap();
+                let limit: usize = query_param(path, "limit").unwrap().parse().unwrap();
+                listing_pages.lock().unwrap().push(path.to_string());
+                // 150 listings priced from 0.01 to 1.5 SOL
+                let page: Vec<String> = (1..=150)
+                    .skip(offset)
+                    .take(limit)
+                    .map(listing_json)
+                    .collect();
+                return (200, format!("[{}]", page.join(",")));
+            }
+
+            assert!(path.starts_with("/instructions/buy_now"), "{path}");
+            let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            max_in_flight.fetch_max(current, Ordering::SeqCst);
+            thread::sleep(Duration::from_millis(20));
+            in_flight.fetch_sub(1, Ordering::SeqCst);
+            match query_param(path, "tokenMint") {
+                Some("mint3") => (
+                    400,
+                    r#"{"error":{"message":"listing is no longer available"}}"#.to_string(),
+                ),
+                _ => (200, INSTRUCTION.to_string()),
+            }
+        }
+    });
+    let client = Client::new().with_api_base(base);
+
+    let request = SweepRequestArgs::default()
+        .collection_symbol("okay_bears")
+        .buyer("buyer")
+        .budget(1.0)
+        .max_listings(150)
+        .concurrency(2_usize)
+        .build()
+        .unwrap();
+    let plan = client.sweep().plan(request).await.unwrap();
+
+    let pages = listing_pages.lock().unwrap();
+    assert_eq!(pages.len(), 2, "{pages:?}");
+    assert!(pages[0].contains("offset=0&limit=100"), "{}", pages[0]);
+    assert!(pages[1].contains("offset=100&limit=50"), "{}", pages[1]);
+    assert!(pages[0].contains("maxPrice=1"), "{}", pages[0]);
+
+    // 0.01 + 0.02 + ... + 0.13 = 0.91 SOL fits in the budget, mint3 failed
+    let mints: Vec<&str> = plan
+        .items
+        .iter()
+        .map(|item| item.listing.token_mint.as_str())
+        .collect();
+    let expected: Vec<String> = (1..=13)
+        .filter(|i| *i != 3)
+        .map(|i| format!("mint{i}"))
+        .collect();
+    assert_eq!(mints, expected);
+    assert_eq!(plan.items[0].instruction.tx.data, vec![1]);
+    assert_eq!(plan.total_cost, 0.88);
+
+    assert_eq!(plan.skipped.len(), 138);
+    let failed = &plan.skipped[137];
+    assert_eq!(failed.listing.token_mint, "mint3");
+    assert!(
+        matches!(&failed.reason, SkipReason::InstructionFailed(e) if e.contains("no longer available")),
+        "{:?}",
+        failed.reason
+    );
+    assert!(plan.skipped[..137]
+        .iter()
+        .all(|skipped| skipped.reason == SkipReason::OverBudget));
+    assert_eq!(plan.skipped[0].listing.token_mint, "mint14");
+
+    let max_in_flight = max_in_flight.load(Ordering::SeqCst);
+    assert_eq!(max_in_flight, 2);
+}
@@ -0,0 +1,87 @@
+use std::{
+    io::Write,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+use magiceden_rs::Client;
+use tracing_subscriber::fmt::{format::FmtSpan, MakeWriter};
+
+mod common;
+
+#[derive(Clone, Default)]
+struct Logs(Arc<Mutex<Vec<u8>>>);
+
+impl Write for Logs {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a> MakeWriter<'a> for Logs {
+    type Writer = Logs;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        self.clone()
+    }
+}
+
+#[tokio::test]
+async fn request_span_records_attempts_and_hides_api_key() {
+    let logs = Logs::default();
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::DEBUG)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_ansi(false)
+        .with_writer(logs.clone())
+        .finish();
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    let requests = Arc::new(AtomicUsize::new(0));
+    let base = common::serve({
+        let requests = requests.clone();
+        move |_| match requests.fetch_add(1, Ordering::SeqCst) {
+            0 => (
+                429,
+                r#"{"error":{"message":"slow down","type":null}}"#.to_string(),
+            ),
+            _ => (200, r#"{"symbol":"okay_bears"}"#.to_string()),
+        }
+    });
+
+    let backoff = backoff::ExponentialBackoff {
+        initial_interval: Duration::from_millis(10),
+        ..Default::default()
+    };
+    let client = Client::new()
+        .with_api_base(base)
+        .with_api_key("secret-key")
+        .unwrap()
+        .with_backoff(backoff);
+    let stats = client.collections().stats("okay_bears").await.unwrap();
+    assert_eq!(stats.symbol, "okay_bears");
+
+    let logs = String::from_utf8(logs.0.lock().unwrap().clone()).unwrap();
+    assert!(logs.contains("stats{symbol=\"okay_bears\"}"), "{logs}");
+    assert!(logs.contains("method=GET"), "{logs}");
+    assert!(
+        logs.contains("endpoint=/collections/{symbol}/stats"),
+        "{logs}"
+    );
+    assert!(
+        logs.contains("path=/collections/okay_bears/stats"),
+        "{logs}"
+    );
+    assert!(logs.contains("retry_delay_ms="), "{logs}");
+    assert!(logs.contains("response attempt=2 status=200"), "{logs}");
+    assert!(logs.contains("latency_ms="), "{logs}");
+    assert!(!logs.contains("secret-key"), "{logs}");
+}
@@ -0,0 +1,141 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+use futures::{Stream, StreamExt};
+use magiceden_rs::{
+    types::{CollectionListingsRequest, ListingEvent},
+    Client,
+};
+
+mod common;
+
+const INTERVAL: Duration = Duration::from_millis(10);
+
+fn activity(signature: usize) -> String {
+    format!(
+        r#"{{"signature":"sig{signature}","type":"buyNow","source":"magiceden_v2","slot":{signature},"blockTime":{signature},"buyerReferral":"","price":1.0}}"#
+    )
+}
+
+fn page(signatures: &[usize]) -> String {
+    let items: Vec<String> = signatures.iter().map(|s| activity(*s)).collect();
+    format!("[{}]", items.join(","))
+}
+
+fn query_param(path: &str, name: &str) -> usize {
+    path.split(['?', '&'])
+        .find_map(|pair| pair.strip_prefix(&format!("{name}=")))
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(0)
+}
+
+fn client(base: String) -> Client {
+    let backoff = backoff::ExponentialBackoff {
+        initial_interval: Duration::from_millis(10),
+        max_interval: Duration::from_millis(10),
+        ..Default::default()
+    };
+    Client::new().with_api_base(base).with_backoff(backoff)
+}
+
+async fn next<S: Stream + Unpin>(stream: &mut S) -> S::Item {
+    tokio::time::timeout(Duration::from_secs(5), stream.next())
+        .await
+        .unwrap()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn watch_activities_dedup_and_gap() {
+    // newest activity id of each poll, activities are returned newest first
+    let polls = Arc::new(AtomicUsize::new(0));
+    let newest = [2, 2, 4, 0, 250];
+    let base = common::serve({
+        let polls = polls.clone();
+        move |path| {
+            let offset = query_param(path, "offset");
+            let limit = query_param(path, "limit");
+            let poll = if offset == 0 {
+                polls.fetch_add(1, Ordering::SeqCst)
+            } else {
+                polls.load(Ordering::SeqCst) - 1
+            };
+            match newest.get(poll).copied().unwrap_or(250) {
+                0 => (500, r#"{"error":{"message":"boom"}}"#.to_string()),
+                newest => {
+                    let ids: Vec<usize> = (1..=newest).rev().skip(offset).take(limit).collect();
+                    (200, page(&ids))
+                }
+            }
+        }
+    });
+
+    let client = client(base);
+    let collections = client.collections();
+    let stream = collections.watch_activities("okay_bears", INTERVAL);
+    let mut stream = Box::pin(stream);
+
+    assert_eq!(next(&mut stream).await.unwrap().signature, "sig3");
+    assert_eq!(next(&mut stream).await.unwrap().signature, "sig4");
+    assert!(next(&mut stream).await.is_err());
+    for id in 5..=250 {
+        assert_eq!(
+            next(&mut stream).await.unwrap().signature,
+            format!("sig{id}")
+        );
+    }
+}
+
+fn listing(token_mint: &str, price: f64) -> String {
+    serde_json::to_string(&common::listing_of(token_mint, "seller", price, "{}")).unwrap()
+}
+
+#[tokio::test]
+async fn watch_listings_events() {
+    let snapshots = Arc::new(Mutex::new(vec![
+        format!("[{},{}]", listing("a", 1.0), listing("b", 2.0)),
+        format!("[{},{}]", listing("a", 1.5), listing("c", 3.0)),
+    ]));
+    let base = common::serve({
+        let snapshots = snapshots.clone();
+        move |_| {
+            let mut snapshots = snapshots.lock().unwrap();
+            let body = if snapshots.len() > 1 {
+                snapshots.remove(0)
+            } else {
+                snapshots[0].clone()
+            };
+            (200, body)
+        }
+    });
+
+    let client = client(base);
+    let collections = client.collections();
+    let stream =
+        collections.watch_listings("okay_bears", CollectionListingsRequest::default(), INTERVAL);
+    let events: Vec<ListingEvent> = tokio::time::timeout(
+        Duration::from_secs(5),
+        stream.map(|event| event.unwrap()).take(3).collect(),
+    )
+    .await
+    .unwrap();
+
+    let mut summary: Vec<String> = events
+        .iter()
+        .map(|event| match event {
+            ListingEvent::Listed { listing } => format!("listed {}", listing.token_mint),
+            ListingEvent::Delisted { listing } => format!("delisted {}", listing.token_mint),
+            ListingEvent::PriceChanged { listing, old, new } => {
+                format!("repriced {} {old} {new}", listing.token_mint)
+            }
+            other => format!("{other:?}"),
+        })
+        .collect();
+    summary.sort();
+    assert_eq!(summary, vec!["delisted b", "listed c", "repriced a 1 1.5"]);
+}